@@ -12,8 +12,6 @@
 mod gpx;
 use gpx::{TrackSegment, Velocity};
 
-use sciutil::units::Float;
-
 /// The contents of an example GPX file, compliant with the requirements for
 /// [`TrackSegment::parse_first_in_file`].
 const WITH_TIME_GPX: &str = include_str!("./data/with_time.gpx");
@@ -22,10 +20,8 @@ fn main() {
     let track_points = TrackSegment::parse_first_in_file(WITH_TIME_GPX.as_bytes());
 
     let position = track_points.degrees_traveled_by_seconds();
-    let velocity = sciutil::statistics::derivatives::first_order_time_shifted(&position)
-        .into_iter()
-        .map(|(t, v)| (t, Velocity::new(v)))
-        .collect::<Vec<_>>();
+    let velocity: Vec<(_, Velocity)> =
+        sciutil::statistics::derivatives::first_order_time_shifted_valued(&position);
 
     let position_desmos = sciutil::display::pairs_to_desmos_list("d", position.as_slice());
     let velocity_desmos = sciutil::display::pairs_to_desmos_list("v", velocity.as_slice());
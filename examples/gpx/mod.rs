@@ -26,8 +26,8 @@ impl Coordinates {
     #[must_use]
     pub fn midpoint(&self, other: &Self) -> Self {
         Self {
-            latitude: self.latitude.get().midpoint(other.longitude.get()).into(),
-            longitude: self.longitude.get().midpoint(other.longitude.get()).into(),
+            latitude: self.latitude.midpoint(other.latitude),
+            longitude: self.longitude.midpoint(other.longitude),
         }
     }
 
@@ -7,10 +7,24 @@
 // <https://mozilla.org/MPL/2.0/>.
 
 //! `display`: Miscellaneous facilities for pretty-printing things.
+//!
+//! With `--features plotters`, `plot_pairs` also renders line charts to PNG files.
 
-use crate::units::Float;
+#[cfg(feature = "plotters")]
+mod plot;
 
-use std::fmt::{Display, Write};
+#[cfg(feature = "plotters")]
+pub use plot::{PlotError, plot_pairs};
+
+use crate::{
+    rounding::{self, digits::DigitsValue},
+    units::{Float, FloatDisplay, UncertainFloat},
+};
+
+use std::{
+    fmt::{Display, Write},
+    num::NonZeroUsize,
+};
 
 /// Formats a list of values in a form that [Desmos](https://desmos.com/calculator) will accept as
 /// a list variable.
@@ -71,3 +85,297 @@ pub fn pairs_to_desmos_list<T: Float, F: Float>(variable_name: &str, list: &[(T,
 
     to_desmos_list(variable_name, list.as_slice())
 }
+
+/// Formats several named series of pairs at once, one [`pairs_to_desmos_list`] per line.
+///
+/// This is convenient for pasting a set of related series (like position, velocity, and
+/// acceleration) into Desmos at once. See [`to_desmos_list`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::display::named_series_to_desmos;
+/// #
+/// let position = [(0.0, 0.0), (1.0, 2.0), (2.0, 8.0)];
+/// let velocity = [(0.0, 2.0), (1.0, 4.0), (2.0, 6.0)];
+///
+/// assert_eq!(
+///     named_series_to_desmos(&[("d", &position), ("v", &velocity)]),
+///     "d = [(0,0),(1,2),(2,8)]\nv = [(0,2),(1,4),(2,6)]",
+/// );
+/// ```
+#[must_use]
+pub fn named_series_to_desmos<T: Float>(series: &[(&str, &[(T, f64)])]) -> String {
+    series
+        .iter()
+        .map(|(variable_name, list)| pairs_to_desmos_list(variable_name, list))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats a series of points with (possibly asymmetric) lower and upper bounds as three Desmos
+/// lists, one per line: the `T` values, the lower bounds, and the upper bounds.
+///
+/// In Desmos, `x_name`, `low_name`, and `high_name` can then be used to draw a shaded confidence
+/// band or prediction interval, e.g., with `(x, y)` where `low <= y <= high`. See
+/// [`to_desmos_list`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::display::uncertain_to_desmos_bounds;
+/// #
+/// let list = [(0.0, 0.5, 2.0), (1.5, 2.25, 3.0)];
+///
+/// assert_eq!(
+///     uncertain_to_desmos_bounds("x", "l", "h", &list),
+///     "x = [0,1.5]\nl = [0.5,2.25]\nh = [2,3]",
+/// );
+/// ```
+#[must_use]
+pub fn uncertain_to_desmos_bounds<T: Float>(
+    x_name: &str,
+    low_name: &str,
+    high_name: &str,
+    list: &[(T, f64, f64)],
+) -> String {
+    let x = list.iter().map(|(t, _, _)| t.get()).collect::<Vec<_>>();
+    let low = list.iter().map(|&(_, low, _)| low).collect::<Vec<_>>();
+    let high = list.iter().map(|&(_, _, high)| high).collect::<Vec<_>>();
+
+    [
+        to_desmos_list(x_name, &x),
+        to_desmos_list(low_name, &low),
+        to_desmos_list(high_name, &high),
+    ]
+    .join("\n")
+}
+
+/// Wrapper function for [`to_desmos_list`] to display pairs of [`Float`]s, each rounded to
+/// `sig_figs` significant figures.
+///
+/// This keeps the output readable where [`pairs_to_desmos_list`] would print every digit of an
+/// [`f64`]. Non-finite values are printed as-is (see [`DigitsValue`]).
+///
+/// See [`to_desmos_list`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::num::NonZeroUsize;
+/// #
+/// # use sciutil::display::pairs_to_desmos_list_rounded;
+/// #
+/// let list = [(0.1 + 0.2, 1.0 / 3.0), (1_234.567_89, -0.000_987_654_321)];
+///
+/// assert_eq!(
+///     pairs_to_desmos_list_rounded("p", &list, NonZeroUsize::new(3).unwrap()),
+///     "p = [(0.300,0.333),(1230,-0.000988)]",
+/// );
+///
+/// // Rounding up can carry into a new digit without adding a significant figure.
+/// assert_eq!(
+///     pairs_to_desmos_list_rounded("p", &[(9.99, 0.099_9)], NonZeroUsize::new(2).unwrap()),
+///     "p = [(10,0.10)]",
+/// );
+/// ```
+#[must_use]
+pub fn pairs_to_desmos_list_rounded<T: Float, F: Float>(
+    variable_name: &str,
+    list: &[(T, F)],
+    sig_figs: NonZeroUsize,
+) -> String {
+    let round = |value: f64| match DigitsValue::<f64>::from_f64(value) {
+        DigitsValue::Finite(digits) => digits.rounded(sig_figs).to_string(),
+        non_finite => non_finite.to_string(),
+    };
+
+    let list = list
+        .iter()
+        .map(|(t, f)| format!("({},{})", round(t.get()), round(f.get())))
+        .collect::<Vec<_>>();
+
+    to_desmos_list(variable_name, list.as_slice())
+}
+
+/// Formats a measurement with its uncertainty and unit, rounded to its significant figures.
+///
+/// This is an alias for [`rounding::round_with_uncertainty_with_units`], which rounds using
+/// [`rounding::round_with_uncertainty`], then appends the symbol of the unit of `F` (if `F` has
+/// one, see [`FloatDisplay`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{
+/// #     display::format_measurement,
+/// #     units::{
+/// #         Float, Meters, Seconds, UncertainFloat,
+/// #         composition::{Power, UnitList, UnitListNull, Valued},
+/// #     },
+/// # };
+/// #
+/// // A length.
+/// let length = UncertainFloat::new(
+///     Valued::<f64, Meters>::new(1_024.05),
+///     Valued::<f64, Meters>::new(0.015_555_312),
+/// );
+/// assert_eq!(format_measurement(&length), "1024.05 ± 0.016 m");
+///
+/// // A velocity, using a composite unit.
+/// type Velocity = Valued<f64, UnitList<Power<Seconds, -1>, UnitList<Meters, UnitListNull>>>;
+/// let velocity = UncertainFloat::new(Velocity::new(12.345), Velocity::new(0.42));
+/// assert_eq!(format_measurement(&velocity), "12.3 ± 0.4 m s^(-1)");
+///
+/// // Unit-less values.
+/// let unitless = UncertainFloat::new(1_024.05, 0.015_555_312);
+/// assert_eq!(format_measurement(&unitless), "1024.05 ± 0.016");
+/// ```
+#[must_use]
+pub fn format_measurement<F: FloatDisplay>(value: &UncertainFloat<F>) -> String {
+    rounding::round_with_uncertainty_with_units(value)
+}
+
+/// Formats a list of measurements as CSV, with one column for the values and another for their
+/// uncertainties.
+///
+/// The first row is a header (`value,uncertainty`). Each measurement is rounded consistently with
+/// [`rounding::round_with_uncertainty`]: the uncertainty is rounded to one or two significant
+/// figures, and the value is rounded to the same place.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{display::uncertain_to_csv, units::UncertainFloat};
+/// #
+/// let list = [
+///     UncertainFloat::new(1_024.05, 0.015_555_312),
+///     UncertainFloat::new(9.81, 0.42),
+/// ];
+///
+/// let csv = uncertain_to_csv(&list);
+/// let mut lines = csv.lines();
+/// assert_eq!(lines.next(), Some("value,uncertainty"));
+/// assert_eq!(lines.next(), Some("1024.05,0.016"));
+/// assert_eq!(lines.next(), Some("9.8,0.4"));
+/// assert_eq!(lines.next(), None);
+/// ```
+#[must_use]
+pub fn uncertain_to_csv<F: Float>(list: &[UncertainFloat<F>]) -> String {
+    let mut str = "value,uncertainty\n".to_string();
+
+    for measurement in list {
+        let (value, uncertainty, _) = rounding::round_with_uncertainty_parts(measurement);
+
+        writeln!(str, "{value},{uncertainty}").expect("writing into a `String` should not fail");
+    }
+
+    str
+}
+
+/// Formats a list of points as tab-separated values, with one row per point and one column for
+/// each of `T` and `F`.
+///
+/// There is no header row, and every row (including the last) ends in a newline. This is the
+/// format that spreadsheets like Excel expect when pasting from the clipboard.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::display::pairs_to_tsv;
+/// #
+/// let list = [(0.0, 1.5), (0.5, -2.25), (1.0, 1024.05)];
+///
+/// assert_eq!(pairs_to_tsv(&list), "0\t1.5\n0.5\t-2.25\n1\t1024.05\n");
+/// assert_eq!(pairs_to_tsv::<f64, f64>(&[]), "");
+/// ```
+#[must_use]
+pub fn pairs_to_tsv<T: Float, F: Float>(list: &[(T, F)]) -> String {
+    let mut str = String::new();
+
+    for (t, f) in list {
+        writeln!(str, "{}\t{}", t.get(), f.get()).expect("writing into a `String` should not fail");
+    }
+
+    str
+}
+
+/// Renders a list of values as a text dot plot, for quickly inspecting their distribution.
+///
+/// The range of `list` is split evenly into `width` bins, one per column. Each value is drawn as a
+/// `●` stacked on top of the others in its bin, with the smallest values on the left. The plot
+/// ends with a number line of `─` characters. Every line is exactly `width` characters wide,
+/// including trailing spaces.
+///
+/// Non-finite values are skipped. If every value is the same, they all land in the first column.
+/// Returns an empty string if `width == 0` or if there are no finite values to plot.
+///
+/// For example, `[1, 2, 2, 2.1, 3, 3, 5]` with a width of 5 renders as:
+///
+/// ```txt
+///  ●
+///  ●●
+/// ●●● ●
+/// ─────
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::display::dot_plot;
+/// #
+/// let list = [1.0, 2.0, 2.0, 2.1, 3.0, 3.0, 5.0];
+///
+/// let plot = dot_plot(&list, 5);
+/// assert_eq!(plot, [" ●   ", " ●●  ", "●●● ●", "─────"].join("\n"));
+///
+/// for line in plot.lines() {
+///     assert_eq!(line.chars().count(), 5);
+/// }
+/// ```
+#[must_use]
+pub fn dot_plot<F: Float>(list: &[F], width: usize) -> String {
+    let values = list
+        .iter()
+        .map(Float::get)
+        .filter(|value| value.is_finite())
+        .collect::<Box<_>>();
+
+    if width == 0 || values.is_empty() {
+        return String::new();
+    }
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let mut bins = vec![0_usize; width];
+    for value in values {
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss,
+            reason = "the ratio is in `0.0..=1.0`, and then the bin is clamped to the width"
+        )]
+        let bin = if max > min {
+            (((value - min) / (max - min)) * width as f64) as usize
+        } else {
+            0
+        };
+
+        bins[bin.min(width - 1)] += 1;
+    }
+
+    let height = bins.iter().copied().max().unwrap_or(0);
+    let mut str = String::new();
+
+    for row in (1..=height).rev() {
+        str.extend(
+            bins.iter()
+                .map(|&count| if count >= row { '●' } else { ' ' }),
+        );
+        str.push('\n');
+    }
+    str.extend(std::iter::repeat_n('─', width));
+
+    str
+}
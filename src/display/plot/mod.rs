@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MPL-2.0
+//
+// Copyright © 2025 RemasteredArch
+//
+// This Source Code Form is subject to the terms of the Mozilla Public License, version 2.0. If a
+// copy of the Mozilla Public License was not distributed with this file, You can obtain one at
+// <https://mozilla.org/MPL/2.0/>.
+
+//! `plot`: Render plots to image files with [`plotters`][`::plotters`], enabled with
+//! `--features plotters`.
+
+#[cfg(test)]
+mod test;
+
+use std::{ops::Range, path::Path};
+
+use plotters::prelude::{BLUE, BitMapBackend, ChartBuilder, IntoDrawingArea, LineSeries, WHITE};
+use thiserror::Error;
+
+use crate::units::Float;
+
+/// The width and height of plots, in pixels.
+const SIZE: (u32, u32) = (800, 600);
+
+/// The error given when a plot could not be rendered, see [`plot_pairs`].
+#[derive(Error, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PlotError {
+    /// There were no finite points to plot.
+    #[error("received no finite points to plot")]
+    Empty,
+
+    /// [`plotters`][`::plotters`] failed to draw or save the plot, with its error message.
+    #[error("failed to draw plot: {0}")]
+    Draw(String),
+}
+
+/// Renders a list of points as a line chart, saved as a PNG at `path`.
+///
+/// The axes are fit to the smallest and largest finite `T` and `F` values. Points with non-finite
+/// values are skipped.
+///
+/// # Errors
+///
+/// - Returns [`PlotError::Empty`] if `list` has no points with finite `T` and `F` values.
+/// - Returns [`PlotError::Draw`] if the plot could not be drawn or the file could not be written.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use sciutil::display::plot_pairs;
+/// #
+/// let list = (0..=20)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.1;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+///
+/// plot_pairs(&list, "sin.png").unwrap();
+/// ```
+pub fn plot_pairs<T: Float, F: Float>(
+    list: &[(T, F)],
+    path: impl AsRef<Path>,
+) -> Result<(), PlotError> {
+    let points = list
+        .iter()
+        .map(|(t, f)| (t.get(), f.get()))
+        .filter(|(t, f)| t.is_finite() && f.is_finite())
+        .collect::<Box<_>>();
+
+    let t_range = range(points.iter().map(|&(t, _)| t)).ok_or(PlotError::Empty)?;
+    let f_range = range(points.iter().map(|&(_, f)| f)).ok_or(PlotError::Empty)?;
+
+    let draw_error = |error: &dyn std::error::Error| PlotError::Draw(error.to_string());
+
+    let root = BitMapBackend::new(path.as_ref(), SIZE).into_drawing_area();
+    root.fill(&WHITE).map_err(|error| draw_error(&error))?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .margin(20)
+        .build_cartesian_2d(t_range, f_range)
+        .map_err(|error| draw_error(&error))?;
+    chart
+        .draw_series(LineSeries::new(points.iter().copied(), &BLUE))
+        .map_err(|error| draw_error(&error))?;
+
+    root.present().map_err(|error| draw_error(&error))
+}
+
+/// Finds the range from the smallest to the largest of `values`, widening it if every value is
+/// the same so that it isn't empty.
+///
+/// Returns [`None`] if `values` is empty.
+fn range(values: impl Iterator<Item = f64>) -> Option<Range<f64>> {
+    let (min, max) = values.fold(None, |range: Option<(f64, f64)>, value| {
+        Some(range.map_or((value, value), |(min, max)| {
+            (min.min(value), max.max(value))
+        }))
+    })?;
+
+    if max > min {
+        Some(min..max)
+    } else {
+        Some(min - 1.0..max + 1.0)
+    }
+}
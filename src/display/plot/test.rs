@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MPL-2.0
+//
+// Copyright © 2025 RemasteredArch
+//
+// This Source Code Form is subject to the terms of the Mozilla Public License, version 2.0. If a
+// copy of the Mozilla Public License was not distributed with this file, You can obtain one at
+// <https://mozilla.org/MPL/2.0/>.
+
+use super::{PlotError, plot_pairs};
+
+#[test]
+fn plot_pairs_writes_png() {
+    let list: Box<[(f64, f64)]> = (0..=20)
+        .map(|i| {
+            let t = f64::from(i) * 0.1;
+            (t, t.sin())
+        })
+        .collect();
+
+    let path = std::env::temp_dir().join(format!("sciutil-plot-{}.png", std::process::id()));
+    plot_pairs(&list, &path).unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // Every PNG starts with the same signature.
+    assert!(bytes.len() > 8);
+    assert_eq!(
+        bytes[..8],
+        [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n']
+    );
+}
+
+#[test]
+fn plot_pairs_empty() {
+    let path = std::env::temp_dir().join("sciutil-plot-empty.png");
+
+    assert_eq!(plot_pairs::<f64, f64>(&[], &path), Err(PlotError::Empty));
+    assert_eq!(plot_pairs(&[(f64::NAN, 1.0)], &path), Err(PlotError::Empty));
+    assert!(!path.exists());
+}
@@ -25,6 +25,12 @@ pub struct OutOfBoundsIndexError;
 #[error("received a character or number that is not a valid digit (0-9)")]
 pub struct InvalidDigitError;
 
+/// The error given when the consumer attempted to convert a value to a unit that is not
+/// compatible with its own (e.g., from meters to seconds).
+#[derive(Error, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[error("received a unit that is incompatible with the unit of the value")]
+pub struct IncompatibleUnitsError;
+
 /// The error given when the consumer provided a float (probably an [`f64`]) that is
 /// either [`NaN`] or [infinite] where a [zero], [normal], or [subnormal] float was expected.
 ///
@@ -39,3 +45,12 @@ pub enum InvalidFloatError {
     Nan,
     Infinite,
 }
+
+/// The error given when the consumer provided a list of `(T, F)` pairs that is not sorted by
+/// ascending (non-decreasing) `T` values, where a sorted list was expected.
+#[derive(Error, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[error("received a list that is not sorted by ascending `T` values, starting at index {index}")]
+pub struct UnsortedError {
+    /// The index of the first `T` value that is smaller than the one before it.
+    pub index: usize,
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MPL-2.0
+//
+// Copyright © 2025 RemasteredArch
+//
+// This Source Code Form is subject to the terms of the Mozilla Public License, version 2.0. If a
+// copy of the Mozilla Public License was not distributed with this file, You can obtain one at
+// <https://mozilla.org/MPL/2.0/>.
+
+//! `interop`: Conversions to and from the types of other crates in the scientific Rust ecosystem.
+//!
+//! Each submodule is gated behind a feature of the same name:
+//!
+//! - `ndarray` (`--features ndarray`): Bridges between slices and `ndarray::Array1`.
+
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MPL-2.0
+//
+// Copyright © 2025 RemasteredArch
+//
+// This Source Code Form is subject to the terms of the Mozilla Public License, version 2.0. If a
+// copy of the Mozilla Public License was not distributed with this file, You can obtain one at
+// <https://mozilla.org/MPL/2.0/>.
+
+//! `ndarray`: Conversions between slices and one-dimensional [`ndarray`][`::ndarray`] arrays.
+//!
+//! Sciutil's [`statistics`][`crate::statistics`] and
+//! [`derivatives`][`crate::statistics::derivatives`] functions operate on slices. These helpers
+//! let them consume [`ArrayView1`]s and produce [`Array1`]s without manual conversion.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use ndarray::array;
+//! #
+//! # use sciutil::{interop::ndarray::from_ndarray, statistics};
+//! #
+//! let array = array![2.0, 3.0, 4.0];
+//! assert_eq!(statistics::mean(&from_ndarray(array.view())), 3.0);
+//! ```
+
+#[cfg(test)]
+mod test;
+
+use std::borrow::Cow;
+
+use ndarray::{Array1, ArrayView1, Zip};
+
+use crate::units::Float;
+
+/// Converts an [`ArrayView1`] into a slice for use with sciutil's functions.
+///
+/// This borrows the array's data if it is contiguous and in standard order, and copies it
+/// otherwise (e.g., for a view of every other element).
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// #
+/// # use ndarray::{array, s};
+/// #
+/// # use sciutil::interop::ndarray::from_ndarray;
+/// #
+/// let array = array![1.0, 2.0, 3.0, 4.0];
+///
+/// assert!(matches!(from_ndarray(array.view()), Cow::Borrowed([1.0, 2.0, 3.0, 4.0])));
+/// assert_eq!(from_ndarray(array.slice(s![..;2])).as_ref(), [1.0, 3.0]);
+/// ```
+#[must_use]
+pub fn from_ndarray(array: ArrayView1<'_, f64>) -> Cow<'_, [f64]> {
+    array
+        .to_slice()
+        .map_or_else(|| Cow::Owned(array.to_vec()), Cow::Borrowed)
+}
+
+/// Converts a slice of [`Float`]s into an owned [`Array1`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ndarray::array;
+/// #
+/// # use sciutil::{interop::ndarray::to_ndarray, units::{Float, Seconds, composition::Valued}};
+/// #
+/// let list = [1.0, 2.0].map(Valued::<f64, Seconds>::new);
+/// assert_eq!(to_ndarray(&list), array![1.0, 2.0]);
+/// ```
+#[must_use]
+pub fn to_ndarray<F: Float>(list: &[F]) -> Array1<f64> {
+    list.iter().map(Float::get).collect()
+}
+
+/// Zips two [`ArrayView1`]s into a list of pairs, for use with sciutil's
+/// [`derivatives`][`crate::statistics::derivatives`].
+///
+/// # Errors
+///
+/// Returns [`None`] if the two arrays are not the same length.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ndarray::array;
+/// #
+/// # use sciutil::{interop::ndarray::pairs_from_ndarray, statistics::derivatives};
+/// #
+/// let t = array![0.0, 1.0, 2.0];
+/// let f = array![0.0, 2.0, 4.0];
+///
+/// let list = pairs_from_ndarray(t.view(), f.view()).unwrap();
+/// assert_eq!(derivatives::first_order(&list), [(0.0, 2.0), (1.0, 2.0), (2.0, 2.0)].into());
+///
+/// assert_eq!(pairs_from_ndarray(t.view(), array![1.0].view()), None);
+/// ```
+#[must_use]
+pub fn pairs_from_ndarray(
+    independent: ArrayView1<'_, f64>,
+    dependent: ArrayView1<'_, f64>,
+) -> Option<Box<[(f64, f64)]>> {
+    if independent.len() != dependent.len() {
+        return None;
+    }
+
+    Some(
+        independent
+            .iter()
+            .zip(dependent.iter())
+            .map(|(&t, &f)| (t, f))
+            .collect(),
+    )
+}
+
+/// Unzips a list of pairs (such as the output of a
+/// [`derivatives`][`crate::statistics::derivatives`] function) into two owned [`Array1`]s.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ndarray::array;
+/// #
+/// # use sciutil::interop::ndarray::pairs_to_ndarray;
+/// #
+/// let (t, f) = pairs_to_ndarray(&[(0.0, 1.0), (1.0, 3.0)]);
+/// assert_eq!(t, array![0.0, 1.0]);
+/// assert_eq!(f, array![1.0, 3.0]);
+/// ```
+#[must_use]
+pub fn pairs_to_ndarray<T: Float, F: Float>(list: &[(T, F)]) -> (Array1<f64>, Array1<f64>) {
+    let mut independent = Array1::zeros(list.len());
+    let mut dependent = Array1::zeros(list.len());
+
+    Zip::from(&mut independent)
+        .and(&mut dependent)
+        .and(list)
+        .for_each(|t, f, (list_t, list_f)| {
+            *t = list_t.get();
+            *f = list_f.get();
+        });
+
+    (independent, dependent)
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MPL-2.0
+//
+// Copyright © 2025 RemasteredArch
+//
+// This Source Code Form is subject to the terms of the Mozilla Public License, version 2.0. If a
+// copy of the Mozilla Public License was not distributed with this file, You can obtain one at
+// <https://mozilla.org/MPL/2.0/>.
+
+use ndarray::{Array1, array, s};
+
+use crate::statistics;
+
+fn eq(lhs: f64, rhs: f64) {
+    assert!((lhs - rhs).abs() < f64::EPSILON, "{lhs} != {rhs}");
+}
+
+#[test]
+fn round_trip_mean() {
+    let array = array![2.0, 3.0, 4.0, 5.0];
+
+    let list = super::from_ndarray(array.view());
+    eq(statistics::mean(&list), 3.5);
+    assert_eq!(super::to_ndarray(&list), array);
+
+    // Non-contiguous views are copied.
+    let every_other = super::from_ndarray(array.slice(s![..;2]));
+    eq(statistics::mean(&every_other), 3.0);
+    assert_eq!(super::to_ndarray(&every_other), array![2.0, 4.0]);
+
+    assert!(statistics::mean(&super::from_ndarray(Array1::zeros(0).view())).is_nan());
+}
+
+#[test]
+fn round_trip_pairs() {
+    let t = array![0.0, 1.0, 2.0, 3.0];
+    let f = array![0.0, 1.0, 4.0, 9.0];
+
+    let list = super::pairs_from_ndarray(t.view(), f.view()).unwrap();
+    assert_eq!(super::pairs_to_ndarray(&list), (t, f));
+}
@@ -16,6 +16,9 @@
 //! - [`statistics`]: List operations for statistics.
 //!   - [`statistics::derivatives`]: A few forms of numeric derivatives.
 //! - [`display`]: Miscellaneous facilities for pretty-printing things.
+//!   - `plotters`: Renders line charts to PNG files with `plotters`.
+//! - [`interop`]: Conversions to and from the types of other crates, each behind a feature flag.
+//!   - `ndarray`: Bridges between slices and `ndarray` arrays.
 //!
 //! ## License
 //!
@@ -27,6 +30,7 @@
 
 pub mod display;
 pub mod err;
+pub mod interop;
 pub mod rounding;
 pub mod statistics;
 pub mod units;
@@ -11,11 +11,17 @@
 //! Everything that isn't [`Digits`] is kept out of [`super`] to keep it from being too long (but
 //! publicly reexported so that the API remains flat).
 
-use crate::{err::InvalidDigitError, units::Float};
+use crate::{
+    err::{InvalidDigitError, InvalidFloatError},
+    units::Float,
+};
 
 use super::Digits;
 
-use std::{fmt::Display, num::NonZeroIsize};
+use std::{
+    fmt::Display,
+    num::{NonZeroIsize, NonZeroUsize},
+};
 
 #[cfg(any(feature = "serde", test))]
 use serde::{Deserialize, Serialize};
@@ -36,7 +42,7 @@ impl Display for Sign {
             Self::Negative => "-",
         };
 
-        write!(f, "{as_str}",)
+        write!(f, "{as_str}")
     }
 }
 
@@ -110,6 +116,38 @@ impl Digit {
             Self::Nine => 9,
         }
     }
+
+    /// Subtracts `other` from [`Self`], wrapping around like a single column of long subtraction.
+    ///
+    /// Returns the difference and whether it had to borrow from the next column, i.e., whether
+    /// `other` was greater than [`Self`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digit;
+    /// #
+    /// assert_eq!(Digit::Five.checked_sub(Digit::Two), (Digit::Three, false));
+    /// assert_eq!(Digit::Two.checked_sub(Digit::Five), (Digit::Seven, true));
+    /// assert_eq!(Digit::Zero.checked_sub(Digit::Zero), (Digit::Zero, false));
+    /// assert_eq!(Digit::Zero.checked_sub(Digit::Nine), (Digit::One, true));
+    /// ```
+    #[expect(clippy::missing_panics_doc, reason = "see `panic` string")]
+    #[must_use]
+    pub const fn checked_sub(self, other: Self) -> (Self, bool) {
+        let borrow = self.get() < other.get();
+        let difference = if borrow {
+            self.get() + 10 - other.get()
+        } else {
+            self.get() - other.get()
+        };
+
+        let Ok(digit) = Self::new(difference) else {
+            panic!("the difference of two digits is between zero and nine after borrowing");
+        };
+
+        (digit, borrow)
+    }
 }
 
 impl TryFrom<u8> for Digit {
@@ -141,6 +179,28 @@ impl TryFrom<char> for Digit {
     }
 }
 
+/// Converts a string of base-ten digits into a boxed slice of [`Digit`]s.
+///
+/// # Errors
+///
+/// Returns [`InvalidDigitError`] on the first character that is not a digit from 0--9. Note that
+/// this includes signs and decimal points.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::rounding::digits::{Digit, digits_from_str};
+/// #
+/// assert_eq!(
+///     digits_from_str("105").unwrap(),
+///     [Digit::One, Digit::Zero, Digit::Five].into(),
+/// );
+/// assert!(digits_from_str("1.5").is_err());
+/// ```
+pub fn digits_from_str(s: &str) -> Result<Box<[Digit]>, InvalidDigitError> {
+    s.chars().map(Digit::try_from).collect()
+}
+
 impl From<Digit> for char {
     fn from(digit: Digit) -> Self {
         const ASCII_ZERO: u8 = 0b0011_0000;
@@ -232,12 +292,7 @@ impl<'a> DigitSlice<'a> {
     pub fn add(&self, mut value: u32) -> Box<[Digit]> {
         value += u32::from(self);
 
-        // `value.ilog10()` panics if `value == 0`, so we special case that.
-        if value == 0 {
-            return [Digit::Zero].to_vec().into_boxed_slice();
-        }
-        // The number of digits in `value`.
-        let len = (value.ilog10() + 1) as usize;
+        let len = digit_count(value);
 
         let mut digits = [Digit::Zero].repeat(len).into_boxed_slice();
         for i in (0..len).rev() {
@@ -262,6 +317,17 @@ impl<'a> DigitSlice<'a> {
     }
 }
 
+/// The number of decimal digits needed to write `value`, without going through a string.
+///
+/// Zero is written as a single digit.
+pub(super) const fn digit_count(value: u32) -> usize {
+    // `value.ilog10()` panics if `value == 0`, so we special case that.
+    match value.checked_ilog10() {
+        Some(log) => log as usize + 1,
+        None => 1,
+    }
+}
+
 impl From<&DigitSlice<'_>> for u32 {
     #[expect(
         clippy::cast_possible_truncation,
@@ -304,6 +370,126 @@ pub type SplitFloat = (Sign, Box<[Digit]>, Box<[Digit]>);
 /// ```
 pub type Place = NonZeroIsize;
 
+/// Represents any floating-point value, including those that [`Digits`] cannot represent.
+///
+/// [`Digits::new`] panics on [`NaN`] and [infinite] values, which is inconvenient for pipelines
+/// that would rather carry such values through to be displayed. [`Self::from_f64`] never panics,
+/// and the [`Display`] implementation for [`Self`] renders non-finite values as `"NaN"`, `"inf"`,
+/// or `"-inf"`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::rounding::digits::{DigitsValue, Sign};
+/// #
+/// assert_eq!(DigitsValue::<f64>::from_f64(1024.05).to_string(), "1024.05");
+/// assert_eq!(DigitsValue::<f64>::from_f64(f64::NAN).to_string(), "NaN");
+/// assert_eq!(DigitsValue::<f64>::from_f64(f64::INFINITY).to_string(), "inf");
+/// assert_eq!(DigitsValue::<f64>::from_f64(f64::NEG_INFINITY).to_string(), "-inf");
+///
+/// assert_eq!(
+///     DigitsValue::<f64>::from_f64(f64::NEG_INFINITY),
+///     DigitsValue::Infinite(Sign::Negative),
+/// );
+/// ```
+///
+/// [`NaN`]: std::num::FpCategory::Nan
+/// [infinite]: std::num::FpCategory::Infinite
+pub enum DigitsValue<F: Float> {
+    /// A finite value.
+    Finite(Digits<F>),
+    /// Not a number.
+    NaN,
+    /// Positive or negative infinity.
+    Infinite(Sign),
+}
+
+impl<F: Float> DigitsValue<F> {
+    /// Parses a floating-point value into a [`Self`].
+    ///
+    /// Unlike [`Digits::new`], this never panics.
+    #[must_use]
+    pub fn new(value: &F) -> Self {
+        Self::from_f64(value.get())
+    }
+
+    /// Parses an [`f64`] into a [`Self`].
+    ///
+    /// Unlike [`Digits::try_from`], this never fails.
+    #[must_use]
+    pub fn from_f64(value: f64) -> Self {
+        match Digits::try_from(value) {
+            Ok(digits) => Self::Finite(digits),
+            Err(InvalidFloatError::Nan) => Self::NaN,
+            Err(InvalidFloatError::Infinite) if value.is_sign_negative() => {
+                Self::Infinite(Sign::Negative)
+            }
+            Err(InvalidFloatError::Infinite) => Self::Infinite(Sign::Positive),
+        }
+    }
+
+    /// Returns the contained [`Digits`] if [`Self`] is finite.
+    #[must_use]
+    pub const fn finite(&self) -> Option<&Digits<F>> {
+        match self {
+            Self::Finite(digits) => Some(digits),
+            Self::NaN | Self::Infinite(_) => None,
+        }
+    }
+}
+
+impl<F: Float> From<Digits<F>> for DigitsValue<F> {
+    fn from(digits: Digits<F>) -> Self {
+        Self::Finite(digits)
+    }
+}
+
+impl<F: Float> Display for DigitsValue<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Finite(digits) => write!(f, "{digits}"),
+            Self::NaN => write!(f, "NaN"),
+            Self::Infinite(Sign::Positive) => write!(f, "inf"),
+            Self::Infinite(Sign::Negative) => write!(f, "-inf"),
+        }
+    }
+}
+
+// See the comment above the manual trait implementations for [`Digits`].
+
+impl<F: Float> Clone for DigitsValue<F> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Finite(digits) => Self::Finite(digits.clone()),
+            Self::NaN => Self::NaN,
+            Self::Infinite(sign) => Self::Infinite(*sign),
+        }
+    }
+}
+
+impl<F: Float> Eq for DigitsValue<F> {}
+
+impl<F: Float> PartialEq for DigitsValue<F> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Finite(lhs), Self::Finite(rhs)) => lhs == rhs,
+            (Self::NaN, Self::NaN) => true,
+            (Self::Infinite(lhs), Self::Infinite(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
+}
+
+impl<F: Float> std::fmt::Debug for DigitsValue<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Finite(digits) => f.debug_tuple("Finite").field(digits).finish(),
+            Self::NaN => write!(f, "NaN"),
+            Self::Infinite(sign) => f.debug_tuple("Infinite").field(sign).finish(),
+        }
+    }
+}
+
 /// The absolute uncertainty in that value.
 pub struct UncertainDigits<F: Float> {
     /// The measured value.
@@ -350,3 +536,160 @@ impl<F: Float> UncertainDigits<F> {
     // }
     // ```
 }
+
+/// Where to round a [`Digits`] to, see [`DisplayRounded`].
+#[cfg_attr(any(feature = "serde", test), derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum RoundTo {
+    /// Round to a given [`Place`], see [`Digits::round_to_place`].
+    Place(Place),
+
+    /// Round to a given number of significant figures, see
+    /// [`Digits::round_to_significant_figures`].
+    SignificantFigures(NonZeroUsize),
+}
+
+impl From<Place> for RoundTo {
+    fn from(place: Place) -> Self {
+        Self::Place(place)
+    }
+}
+
+impl From<NonZeroUsize> for RoundTo {
+    fn from(sig_figs: NonZeroUsize) -> Self {
+        Self::SignificantFigures(sig_figs)
+    }
+}
+
+/// Wraps a [`Digits`] to display it rounded, without rounding it ahead of time.
+///
+/// This is constructed by [`Digits::rounded`], and is intended for use in [`format!`] and
+/// friends, where calling [`Digits::round_to_place`] and then [`ToString::to_string`] is
+/// verbose.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::rounding::digits::{Digits, Place};
+/// #
+/// let value = Digits::<f64>::new(&1024.05);
+///
+/// assert_eq!(format!("{} m", value.rounded(Place::new(-3).unwrap())), "1000 m");
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayRounded<'a, F: Float> {
+    digits: &'a Digits<F>,
+    round_to: RoundTo,
+}
+
+impl<'a, F: Float> DisplayRounded<'a, F> {
+    /// Construct a new instance of [`Self`].
+    #[must_use]
+    pub const fn new(digits: &'a Digits<F>, round_to: RoundTo) -> Self {
+        Self { digits, round_to }
+    }
+
+    /// Returns the wrapped [`Digits`], unrounded.
+    #[must_use]
+    pub const fn digits(&self) -> &'a Digits<F> {
+        self.digits
+    }
+
+    /// Returns where the [`Digits`] will be rounded to.
+    #[must_use]
+    pub const fn round_to(&self) -> RoundTo {
+        self.round_to
+    }
+}
+
+impl<F: Float> Display for DisplayRounded<'_, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.round_to {
+            RoundTo::Place(place) => self.digits.round_to_place_cow(place).fmt(f),
+            // Rounding to the place of the unrounded value would keep an extra figure when rounding
+            // up carries into a new digit (e.g., `9.99` to `10.0`).
+            RoundTo::SignificantFigures(sig_figs) => {
+                self.digits.round_to_significant_figures(sig_figs).fmt(f)
+            }
+        }
+    }
+}
+
+/// Wraps a [`Digits`] to compare and hash it by the number it represents, rather than by its
+/// literal representation.
+///
+/// The [`PartialEq`] and [`std::hash::Hash`] implementations for [`Digits`] consider `1.5` and
+/// `1.50` to be different. This uses [`Digits::eq_value`] and hashes [`Digits::normalized`]
+/// instead, so it is suitable as a key for maps that should treat those as the same.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// #
+/// # use sciutil::rounding::digits::{Digit, Digits, NumericHash, Sign};
+/// #
+/// let one_point_five_zero =
+///     Digits::<f64>::from_parts(Sign::Positive, 1, [Digit::One, Digit::Five, Digit::Zero].into())
+///         .unwrap();
+///
+/// let mut set = HashSet::new();
+/// set.insert(NumericHash::new(Digits::<f64>::new(&1.5)));
+/// assert!(set.contains(&NumericHash::new(one_point_five_zero)));
+/// ```
+pub struct NumericHash<F: Float>(Digits<F>);
+
+impl<F: Float> NumericHash<F> {
+    /// Construct a new instance of [`Self`].
+    #[must_use]
+    pub const fn new(digits: Digits<F>) -> Self {
+        Self(digits)
+    }
+
+    /// Returns the wrapped [`Digits`], as it was given.
+    #[must_use]
+    pub const fn get(&self) -> &Digits<F> {
+        &self.0
+    }
+
+    /// Unwraps [`Self`] into the wrapped [`Digits`], as it was given.
+    #[must_use]
+    pub fn into_inner(self) -> Digits<F> {
+        self.0
+    }
+}
+
+impl<F: Float> From<Digits<F>> for NumericHash<F> {
+    fn from(digits: Digits<F>) -> Self {
+        Self::new(digits)
+    }
+}
+
+// The following implementations are manual implementations of commonly derived traits, see
+// [`Digits`].
+
+impl<F: Float> Clone for NumericHash<F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<F: Float> Eq for NumericHash<F> {}
+
+impl<F: Float> PartialEq for NumericHash<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_value(&other.0)
+    }
+}
+
+impl<F: Float> std::hash::Hash for NumericHash<F> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.normalized().hash(state);
+    }
+}
+
+impl<F: Float> std::fmt::Debug for NumericHash<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NumericHash").field(&self.0).finish()
+    }
+}
@@ -14,14 +14,23 @@
 mod defs;
 mod err;
 
-use std::{cmp::Ordering, fmt::Display, marker::PhantomData, num::FpCategory};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt::{Display, Write},
+    marker::PhantomData,
+    num::{FpCategory, NonZeroUsize},
+};
 
 // Everything that isn't [`Digits`] is kept out of this file to keep it from being too long, but
 // needs to be publicly reexported to keep the API flat.
 pub use defs::*;
 pub use err::*;
 
-use crate::{err::InvalidFloatError, units::Float};
+use crate::{
+    err::InvalidFloatError,
+    units::{Float, FloatDisplay},
+};
 
 #[cfg(any(feature = "serde", test))]
 use serde::{Deserialize, Deserializer, Serialize};
@@ -57,6 +66,20 @@ pub struct Digits<F: Float> {
     #[expect(clippy::struct_field_names, reason = "this is the core of the struct")]
     digits: Box<[Digit]>,
 
+    /// The number of trailing zeros before the dot that are significant.
+    ///
+    /// Zeros after the dot are always significant, but trailing zeros before it are ambiguous:
+    /// `1000` could have anywhere from one to four significant figures. They are only treated as
+    /// significant if they are marked here, see [`Digits::with_significant_figures`].
+    ///
+    /// - For `1000`, `significant_zeros = 0` (one significant figure).
+    /// - For `1000` with three significant figures, `significant_zeros = 2`.
+    #[cfg_attr(
+        any(feature = "serde", test),
+        serde(default, skip_serializing_if = "is_zero")
+    )]
+    significant_zeros: usize,
+
     /// Hold onto the type of the original [`Float`].
     #[cfg_attr(any(feature = "serde", test), serde(skip))]
     phantom: PhantomData<F>,
@@ -97,17 +120,32 @@ impl<'de, F: Float> Deserialize<'de> for Digits<F> {
             ));
         }
 
+        if unchecked.significant_zeros > unchecked.ambiguous_zeros() {
+            return Err(serde::de::Error::custom(
+                "`Digits::significant_zeros` must be no greater than the number of trailing zeros \
+                 before the dot",
+            ));
+        }
+
         // Now assuredly valid.
         Ok(unchecked)
     }
 }
 
+/// Used to skip serializing [`Digits::significant_zeros`] when there are none.
+#[cfg(any(feature = "serde", test))]
+#[expect(clippy::trivially_copy_pass_by_ref, reason = "required by `serde`")]
+const fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
 impl<F: Float> Digits<F> {
     /// Parses a floating-point value into a [`Self`].
     ///
     /// # Panics
     ///
-    /// Panics if `value` is [`FpCategory::Nan`] or [`FpCategory::Infinite`].
+    /// Panics if `value` is [`FpCategory::Nan`] or [`FpCategory::Infinite`]. See [`DigitsValue`]
+    /// for an alternative that represents these values instead of panicking.
     ///
     /// # Examples
     ///
@@ -165,6 +203,7 @@ impl<F: Float> Digits<F> {
             sign,
             dot,
             digits,
+            significant_zeros: 0,
             phantom: PhantomData,
         }
     }
@@ -240,6 +279,7 @@ impl<F: Float> Digits<F> {
             sign,
             dot,
             digits,
+            significant_zeros: 0,
             phantom: PhantomData,
         })
     }
@@ -281,6 +321,172 @@ impl<F: Float> Digits<F> {
             }
     }
 
+    /// Returns the number of significant figures in [`Self`].
+    ///
+    /// This counts every [`Digit`] from the first non-zero [`Digit`] to the last [`Digit`], except
+    /// for trailing zeros before the dot, which are ambiguous. Those are only counted if they were
+    /// marked as significant by [`Self::with_significant_figures`]. If every [`Digit`] is zero,
+    /// this counts the zeros after the dot, or returns one if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// assert_eq!(Digits::<f64>::new(&1024.05).significant_figures(), 6);
+    /// assert_eq!(Digits::<f64>::new(&0.00305).significant_figures(), 3);
+    ///
+    /// // Without more information, trailing zeros before the dot are not significant.
+    /// assert_eq!(Digits::<f64>::new(&1000.0).significant_figures(), 1);
+    /// ```
+    #[must_use]
+    pub fn significant_figures(&self) -> usize {
+        let Some(first_significant_digit) =
+            self.digits.iter().position(|&digit| digit != Digit::Zero)
+        else {
+            return (self.digits.len() - self.dot).max(1);
+        };
+
+        self.digits.len() - first_significant_digit - self.ambiguous_zeros()
+            + self.significant_zeros
+    }
+
+    /// Returns the number of trailing zeros before the dot, which may or may not be significant.
+    ///
+    /// This is zero if there are any digits after the dot or if every [`Digit`] is zero.
+    fn ambiguous_zeros(&self) -> usize {
+        if self.dot < self.digits.len() {
+            return 0;
+        }
+
+        self.trailing_zeros()
+    }
+
+    /// Returns a copy of [`Self`] with exactly `sig_figs` [significant
+    /// figures][`Self::significant_figures`].
+    ///
+    /// - If [`Self`] has more significant figures, it is rounded (see [`Self::round_to_place`]).
+    /// - If [`Self`] has fewer significant figures, it is padded with zeros after the dot.
+    /// - If this needs trailing zeros before the dot to be significant, they are marked as such.
+    ///
+    /// Marked zeros are shown by [`Display`]: if every trailing zero before the dot is
+    /// significant, [`Self`] is written with a trailing dot (`1000.`). If only some of them are,
+    /// [`Self`] is written in scientific notation (`1.00e3`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::num::NonZeroUsize;
+    /// #
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// let sig_figs = |count| NonZeroUsize::new(count).unwrap();
+    /// let thousand = Digits::<f64>::new(&1000.0);
+    ///
+    /// let three = thousand.with_significant_figures(sig_figs(3));
+    /// assert_eq!(three.significant_figures(), 3);
+    /// assert_eq!(three.to_string(), "1.00e3");
+    ///
+    /// let four = thousand.with_significant_figures(sig_figs(4));
+    /// assert_eq!(four.significant_figures(), 4);
+    /// assert_eq!(four.to_string(), "1000.");
+    ///
+    /// let five = thousand.with_significant_figures(sig_figs(5));
+    /// assert_eq!(five.significant_figures(), 5);
+    /// assert_eq!(five.to_string(), "1000.0");
+    ///
+    /// // Rounding still applies.
+    /// let rounded = Digits::<f64>::new(&9.96).with_significant_figures(sig_figs(2));
+    /// assert_eq!(rounded.significant_figures(), 2);
+    /// assert_eq!(rounded.to_string(), "10.");
+    /// ```
+    #[must_use]
+    pub fn with_significant_figures(&self, sig_figs: NonZeroUsize) -> Self {
+        // Rounding up may add a digit (e.g., `9.96` to `10.0`), so round again to the place of
+        // the last significant figure of the result.
+        let rounded = self.round_to_place(self.significant_figure_place(sig_figs));
+        let place = rounded.significant_figure_place(sig_figs);
+        let mut rounded = rounded.round_to_place(place);
+
+        if place.is_positive() {
+            // Pad with zeros after the dot, up to `place`.
+            let len = rounded.dot + place.get().unsigned_abs();
+            if rounded.digits.len() < len {
+                let mut digits = rounded.digits.to_vec();
+                digits.resize(len, Digit::Zero);
+                rounded.digits = digits.into_boxed_slice();
+            }
+        } else {
+            // Zeros from the ones place up to (but not including) `place` are not significant.
+            let insignificant_zeros = place.get().unsigned_abs() - 1;
+            rounded.significant_zeros = rounded
+                .ambiguous_zeros()
+                .saturating_sub(insignificant_zeros);
+        }
+
+        rounded
+    }
+
+    /// Rounds [`Self`] to `figures` significant figures, counting from the first non-zero
+    /// [`Digit`].
+    ///
+    /// This rounds with [`Self::round_to_digit`], so it never adds digits. Unlike
+    /// [`Self::with_significant_figures`], it does not pad [`Self`] with zeros or mark them as
+    /// significant. If rounding up carries into a new digit (e.g., `9.99` to `10.0`), [`Self`] is
+    /// rounded again so that it still has at most `figures` significant figures.
+    ///
+    /// If every [`Digit`] is zero, this returns [`Self::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::num::NonZeroUsize;
+    /// #
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// let figures = |count| NonZeroUsize::new(count).unwrap();
+    ///
+    /// let rounded = Digits::<f64>::new(&0.015555312).round_to_significant_figures(figures(2));
+    /// assert_eq!(rounded.to_string(), "0.016");
+    ///
+    /// let rounded = Digits::<f64>::new(&1024.05).round_to_significant_figures(figures(3));
+    /// assert_eq!(rounded.to_string(), "1020");
+    ///
+    /// // Carrying into a new digit.
+    /// let rounded = Digits::<f64>::new(&9.99).round_to_significant_figures(figures(2));
+    /// assert_eq!(rounded.to_string(), "10");
+    ///
+    /// // Rounding to more digits than there are doesn't change anything.
+    /// let rounded = Digits::<f64>::new(&42.0).round_to_significant_figures(figures(3));
+    /// assert_eq!(rounded.to_string(), "42");
+    /// ```
+    #[must_use]
+    pub fn round_to_significant_figures(&self, figures: NonZeroUsize) -> Self {
+        let last_digit = |digits: &Self| {
+            digits
+                .digits
+                .iter()
+                .position(|digit| digit.get() != 0)
+                .map(|first| first + figures.get() - 1)
+        };
+
+        let Some(digit_index) = last_digit(self) else {
+            return Self::default();
+        };
+        let rounded = self.round_to_digit(digit_index);
+
+        // Carrying may have moved the first non-zero digit up a place (e.g., `9.99` to `10.0`).
+        match last_digit(&rounded) {
+            Some(carried_index)
+                if rounded.digit_index_to_place(carried_index)
+                    != self.digit_index_to_place(digit_index) =>
+            {
+                rounded.round_to_digit(carried_index)
+            }
+            _ => rounded,
+        }
+    }
+
     /// Returns the digit index of the last significant digit in [`Self`] when rounding to one or
     /// two significant figures.
     ///
@@ -352,6 +558,119 @@ impl<F: Float> Digits<F> {
         self.digit_index_to_place(self.last_significant_digit())
     }
 
+    /// Returns the [`Place`] of the last significant [`Digit`] in [`Self`] when rounding to
+    /// `sig_figs` significant figures.
+    ///
+    /// This generalizes [`Self::last_significant_place`], counting `sig_figs` digits from the
+    /// first non-zero [`Digit`] (or from the first [`Digit`], if they are all zero). The returned
+    /// [`Place`] may be beyond the end of [`Self`]'s digits, as in `42` to three significant
+    /// figures being `42.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::num::NonZeroUsize;
+    /// #
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// let sig_figs = |count| NonZeroUsize::new(count).unwrap();
+    ///
+    /// // ```txt
+    /// // 1024.05
+    /// // ^-
+    /// // ```
+    /// assert_eq!(Digits::<f64>::new(&1024.05).significant_figure_place(sig_figs(2)).get(), -3);
+    ///
+    /// // ```txt
+    /// // 42
+    /// // ^--
+    /// // ```
+    /// assert_eq!(Digits::<f64>::new(&42.0).significant_figure_place(sig_figs(3)).get(), 1);
+    /// ```
+    #[must_use]
+    pub fn significant_figure_place(&self, sig_figs: NonZeroUsize) -> Place {
+        let first_significant_digit = self
+            .digits
+            .iter()
+            .position(|digit| digit.get() != 0)
+            .unwrap_or(0);
+
+        self.digit_index_to_place(first_significant_digit + sig_figs.get() - 1)
+    }
+
+    /// Returns the number of zeros before the first significant (non-zero) [`Digit`] in [`Self`].
+    ///
+    /// The zero in the ones place of a number smaller than one is not counted, so `0.05` has one
+    /// leading zero. If every [`Digit`] is zero, they are all treated as leading zeros (except for
+    /// the ones place), and [`Self::trailing_zeros`] is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// // ```txt
+    /// // 0.00305
+    /// //   ^^
+    /// // ```
+    /// assert_eq!(Digits::<f64>::new(&0.00305).leading_zeros(), 2);
+    ///
+    /// // ```txt
+    /// // 30500
+    /// // ```
+    /// assert_eq!(Digits::<f64>::new(&30500.0).leading_zeros(), 0);
+    /// ```
+    #[must_use]
+    pub fn leading_zeros(&self) -> usize {
+        let zeros = self
+            .digits
+            .iter()
+            .take_while(|&&digit| digit == Digit::Zero)
+            .count();
+
+        // Skip the zero in the ones place of numbers smaller than one.
+        if self.dot == 1 {
+            zeros.saturating_sub(1)
+        } else {
+            zeros
+        }
+    }
+
+    /// Returns the number of zeros after the last significant (non-zero) [`Digit`] in [`Self`].
+    ///
+    /// This includes zeros both before and after the dot, so `1.50` has one trailing zero and
+    /// `1500` has two. If every [`Digit`] is zero, this returns zero (see
+    /// [`Self::leading_zeros`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// // ```txt
+    /// // 0.00305
+    /// // ```
+    /// assert_eq!(Digits::<f64>::new(&0.00305).trailing_zeros(), 0);
+    ///
+    /// // ```txt
+    /// // 30500
+    /// //    ^^
+    /// // ```
+    /// assert_eq!(Digits::<f64>::new(&30500.0).trailing_zeros(), 2);
+    /// ```
+    #[must_use]
+    pub fn trailing_zeros(&self) -> usize {
+        if self.digits.iter().all(|&digit| digit == Digit::Zero) {
+            return 0;
+        }
+
+        self.digits
+            .iter()
+            .rev()
+            .take_while(|&&digit| digit == Digit::Zero)
+            .count()
+    }
+
     /// Rounds [`Self`] to the given digit index.
     ///
     /// If `digit_index` is out of range, it will return a copy of [`Self`], unchanged.
@@ -360,13 +679,14 @@ impl<F: Float> Digits<F> {
     ///
     /// - Out of range,
     /// - 0--4,
-    /// - or 5 and the [`Digit`] at `digit_index` is even,
+    /// - or 5, followed only by zeros, and the [`Digit`] at `digit_index` is even,
     ///
     /// It rounds down, simply truncating [`Self`] at `digits_index`.
     ///
     /// If the [`Digit`] at `digit_index + 1` is:
     ///
     /// - 6--9
+    /// - or 5, followed by any non-zero [`Digit`]
     /// - or 5 and the [`Digit`] at `digit_index` is odd
     ///
     /// It rounds up, adding `1` to the [`Digit`] at `digit_index` (carrying tens up as necessary).
@@ -418,7 +738,9 @@ impl<F: Float> Digits<F> {
     /// ```
     #[must_use]
     pub fn round_to_digit(&self, digit_index: usize) -> Self {
-        if digit_index >= self.digits.len() {
+        // Rounding to the last digit (or beyond) never changes anything. Because `self.dot` is at
+        // most `self.digits.len()`, there are no trailing zeros to append either.
+        if digit_index + 1 >= self.digits.len() {
             return self.clone();
         }
 
@@ -445,41 +767,41 @@ impl<F: Float> Digits<F> {
             .copied()
             .unwrap_or(Digit::Zero);
 
-        // Truncate digits beyond `digit_index`.
-        let digits = DigitSlice::new(&self.digits[0..=digit_index]);
-
-        // Round up if necessary.
-        let mut digits = match trailing_digit.get() {
-            0..=4 => digits.into_boxed(),
-            5 if last_digit.get() % 2 == 0 => digits.into_boxed(),
-            _ => digits.add(1),
+        let round_up = match trailing_digit.get() {
+            0..=4 => false,
+            // Only an exact tie rounds to even.
+            5 => {
+                !last_digit.get().is_multiple_of(2)
+                    || self.digits[digit_index + 2..]
+                        .iter()
+                        .any(|&digit| digit != Digit::Zero)
+            }
+            _ => true,
         };
 
-        // If rounding up caused another digit to be added, move the dot one digit to the right.
-        let dot = if digits.len() == digit_index {
-            self.dot + 1
-        } else {
-            self.dot
-        };
+        // Rounding up only adds a leading digit if every digit it carries through is a nine.
+        let carries = round_up
+            && self.digits[0..=digit_index]
+                .iter()
+                .all(|&digit| digit == Digit::Nine);
+
+        // Truncate digits beyond `digit_index`. This is the only allocation, so size it exactly
+        // (leaving room for the carry digit and the trailing zeros), otherwise converting it into
+        // a boxed slice at the end would reallocate to shrink it.
+        let mut digits =
+            Vec::with_capacity(digit_index + 1 + usize::from(carries) + trailing_zeros);
+        digits.extend_from_slice(&self.digits[0..=digit_index]);
 
-        // If the addition return a slice shorter than expected, then there were some leading zeros
-        // that got trimmed.
+        // Round up if necessary, carrying in place. Any leading zeros stay where they are:
         //
         // ```txt
         // 009  Start
-        //  10  Rounded to `digit_index` 1
-        // 010  After restoring missing leading zeros
+        // 010  Rounded to `digit_index` 1
         // ```
-        //
-        // Note that it doesn't restore the same _number_ of leading zeros, it restores the leading
-        // zeros up to the first _place_ they appeared.
-        if digits.len() <= digit_index {
-            let missing_leading_zeros = digit_index + 1 - digits.len();
-
-            let mut vec = [Digit::Zero].repeat(missing_leading_zeros);
-            vec.append(&mut digits.to_vec());
-
-            digits = vec.into_boxed_slice();
+        let mut dot = self.dot;
+        // If rounding up caused another digit to be added, move the dot one digit to the right.
+        if round_up && increment_digits(&mut digits) {
+            dot += 1;
         }
 
         // When rounding to a given digit, we truncate at that digit. If that digit was more
@@ -494,17 +816,14 @@ impl<F: Float> Digits<F> {
         // 102      After rounding
         // 102000   After appending trailing zeros
         // ```
-        if trailing_zeros > 0 {
-            let mut vec = digits.to_vec();
-            vec.append(&mut [Digit::Zero].repeat(trailing_zeros));
-
-            digits = vec.into_boxed_slice();
-        }
+        digits.resize(digits.len() + trailing_zeros, Digit::Zero);
+        let digits = digits.into_boxed_slice();
 
         Self {
             sign: self.sign, // Is this always true?
             digits,
             dot,
+            significant_zeros: 0,
             phantom: PhantomData,
         }
     }
@@ -521,6 +840,8 @@ impl<F: Float> Digits<F> {
     /// Otherwise, behaves the same as calling [`Self::place_to_digit_index`] and
     /// [`Self::round_to_digit`].
     ///
+    /// To avoid cloning [`Self`] when it is left unchanged, see [`Self::round_to_place_cow`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -566,9 +887,40 @@ impl<F: Float> Digits<F> {
     ///     "1024.0",
     /// );
     /// ```
-    #[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
     #[must_use]
     pub fn round_to_place(&self, place: Place) -> Self {
+        self.round_to_place_cow(place).into_owned()
+    }
+
+    /// Like [`Self::round_to_place`], but borrows [`Self`] instead of cloning it if rounding would
+    /// not change it.
+    ///
+    /// This is the case whenever `place` is at or to the right of [`Self`]'s last digit, which is
+    /// common when rounding many values that are already short, e.g., measurements read from a
+    /// data file. Use this over [`Self::round_to_place`] in tight loops to avoid allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// #
+    /// # use sciutil::rounding::digits::{Digits, Place};
+    /// #
+    /// let digits = Digits::<f64>::new(&1024.05);
+    ///
+    /// // Already rounded to the hundredths place, so nothing needs to change.
+    /// let rounded = digits.round_to_place_cow(Place::new(2).unwrap());
+    /// assert!(matches!(rounded, Cow::Borrowed(_)));
+    /// assert_eq!(rounded.to_string(), "1024.05");
+    ///
+    /// // Rounding to the tenths place does produce a new value.
+    /// let rounded = digits.round_to_place_cow(Place::new(1).unwrap());
+    /// assert!(matches!(rounded, Cow::Owned(_)));
+    /// assert_eq!(rounded.to_string(), "1024.0");
+    /// ```
+    #[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
+    #[must_use]
+    pub fn round_to_place_cow(&self, place: Place) -> Cow<'_, Self> {
         // Zero represents the dot for [`Place`] values, but the digit after the dot for digit
         // indices. This accounts for that difference.
         let offset = if place.is_positive() {
@@ -597,35 +949,248 @@ impl<F: Float> Digits<F> {
                 // ```
                 let mut rounded_up = vec![Digit::One];
                 rounded_up.append(&mut [Digit::Zero].repeat(self.dot));
-                return Self {
+                return Cow::Owned(Self {
                     sign: self.sign,
                     dot: self.dot + 1,
                     digits: rounded_up.into_boxed_slice(),
+                    significant_zeros: 0,
                     phantom: PhantomData,
-                };
+                });
             }
 
-            return Self::default();
+            return Cow::Owned(Self::default());
         }
 
         if digit_index < -1 {
-            return Self::default();
+            return Cow::Owned(Self::default());
         }
 
         #[expect(
             clippy::cast_possible_wrap,
             reason = "I've never seen the number of digits in an `f64` surpass `i32::MAX`"
         )]
-        if digit_index >= self.digits.len() as isize {
-            return self.clone();
+        // Rounding to the last digit or beyond is a no-op, see [`Self::round_to_digit`].
+        if digit_index + 1 >= self.digits.len() as isize {
+            return Cow::Borrowed(self);
         }
 
-        self.round_to_digit(
-            self.place_to_digit_index(place)
-                .expect("handled every out-of-range case"),
+        Cow::Owned(
+            self.round_to_digit(
+                self.place_to_digit_index(place)
+                    .expect("handled every out-of-range case"),
+            ),
         )
     }
 
+    /// Wrapper around [`Self::round_to_place`] that also reports whether rounding discarded any
+    /// information.
+    ///
+    /// The returned [`bool`] is `true` if [`Self`] has any non-zero [`Digit`] to the right of
+    /// `place`, meaning that the rounded value is not numerically equal to [`Self`]. Rounding
+    /// away trailing zeros (e.g., `1.50` to `1.5`) does not count as discarding information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::{Digits, Place};
+    /// #
+    /// // ```txt
+    /// // 1024.05
+    /// //       ^
+    /// // 1024.05
+    /// // ```
+    /// let (rounded, changed) =
+    ///     Digits::<f64>::new(&1024.05).round_to_place_checked(Place::new(2).unwrap());
+    /// assert_eq!(rounded.to_string(), "1024.05");
+    /// assert!(!changed);
+    ///
+    /// // ```txt
+    /// // 1024.05
+    /// //      ^
+    /// // 1024.0
+    /// // ```
+    /// let (rounded, changed) =
+    ///     Digits::<f64>::new(&1024.05).round_to_place_checked(Place::new(1).unwrap());
+    /// assert_eq!(rounded.to_string(), "1024.0");
+    /// assert!(changed);
+    /// ```
+    #[must_use]
+    pub fn round_to_place_checked(&self, place: Place) -> (Self, bool) {
+        let changed = self.digits.iter().enumerate().any(|(index, &digit)| {
+            digit != Digit::Zero && self.digit_index_to_place(index) > place
+        });
+
+        (self.round_to_place(place), changed)
+    }
+
+    /// Truncates [`Self`] at the given [`Place`], dropping every [`Digit`] to the right of it
+    /// without any rounding.
+    ///
+    /// - If the provided [`Place`] is to the left of [`Self`]'s first digit, this will return 0.
+    /// - If the provided [`Place`] is to the right of [`Self`]'s last digit, it will return
+    ///   [`Self`], unchanged.
+    ///
+    /// If the [`Place`] is before the dot, the truncated digits before the dot are replaced with
+    /// zeros to preserve the magnitude of [`Self`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::{Digits, Place};
+    /// #
+    /// // ```txt
+    /// // 1.99
+    /// //   ^
+    /// // 1.9
+    /// // ```
+    /// assert_eq!(
+    ///     Digits::<f64>::new(&1.99).truncate_to_place(Place::new(1).unwrap()).to_string(),
+    ///     "1.9",
+    /// );
+    ///
+    /// // ```txt
+    /// // 199
+    /// //  ^
+    /// // 190
+    /// // ```
+    /// assert_eq!(
+    ///     Digits::<f64>::new(&199.0).truncate_to_place(Place::new(-2).unwrap()).to_string(),
+    ///     "190",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn truncate_to_place(&self, place: Place) -> Self {
+        let Ok(digit_index) = self.place_to_digit_index(place) else {
+            // The place is either to the left of the first digit, meaning everything is
+            // truncated, or to the right of the last digit, meaning nothing is.
+            return if place < self.digit_index_to_place(0) {
+                Self::default()
+            } else {
+                self.clone()
+            };
+        };
+
+        let mut digits = self.digits[..=digit_index].to_vec();
+
+        // Replace any truncated digits before the dot with zeros.
+        //
+        // ```txt
+        // 1024.05  `self.digits`
+        //   ^      `digit_index = 2`
+        //    ^     Truncated before the dot
+        // 1020     After appending trailing zeros
+        // ```
+        if digit_index < self.dot {
+            digits.append(&mut [Digit::Zero].repeat(self.dot - 1 - digit_index));
+        }
+
+        Self {
+            sign: self.sign,
+            dot: self.dot,
+            digits: digits.into_boxed_slice(),
+            significant_zeros: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Truncates [`Self`] to a whole number, discarding everything after the dot. This rounds
+    /// toward zero, like [`f64::trunc`], but exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// assert_eq!(Digits::<f64>::new(&1.5).trunc().to_string(), "1");
+    /// assert_eq!(Digits::<f64>::new(&-1.5).trunc().to_string(), "-1");
+    /// assert_eq!(Digits::<f64>::new(&102.99).trunc().to_string(), "102");
+    /// ```
+    #[must_use]
+    pub fn trunc(&self) -> Self {
+        self.truncate_to_place(Self::ONES)
+    }
+
+    /// Rounds [`Self`] to the nearest whole number, using the same rounding as
+    /// [`Self::round_to_place`]: ties round to even, like [`f64::round_ties_even`] (not
+    /// [`f64::round`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// assert_eq!(Digits::<f64>::new(&1.5).round().to_string(), "2");
+    /// assert_eq!(Digits::<f64>::new(&2.5).round().to_string(), "2");
+    /// assert_eq!(Digits::<f64>::new(&-1.5).round().to_string(), "-2");
+    /// assert_eq!(Digits::<f64>::new(&2.51).round().to_string(), "3");
+    /// ```
+    #[must_use]
+    pub fn round(&self) -> Self {
+        self.round_to_place(Self::ONES)
+    }
+
+    /// Rounds [`Self`] up to the nearest whole number (toward positive infinity), like
+    /// [`f64::ceil`], but exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// assert_eq!(Digits::<f64>::new(&1.5).ceil().to_string(), "2");
+    /// assert_eq!(Digits::<f64>::new(&-1.5).ceil().to_string(), "-1");
+    /// assert_eq!(Digits::<f64>::new(&99.01).ceil().to_string(), "100");
+    /// assert_eq!(Digits::<f64>::new(&3.0).ceil().to_string(), "3");
+    /// ```
+    #[must_use]
+    pub fn ceil(&self) -> Self {
+        self.round_away_if(self.sign == Sign::Positive)
+    }
+
+    /// Rounds [`Self`] down to the nearest whole number (toward negative infinity), like
+    /// [`f64::floor`], but exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// assert_eq!(Digits::<f64>::new(&1.5).floor().to_string(), "1");
+    /// assert_eq!(Digits::<f64>::new(&-1.5).floor().to_string(), "-2");
+    /// assert_eq!(Digits::<f64>::new(&-0.25).floor().to_string(), "-1");
+    /// ```
+    #[must_use]
+    pub fn floor(&self) -> Self {
+        self.round_away_if(self.sign == Sign::Negative)
+    }
+
+    /// The ones [`Place`], which whole-number rounding rounds to.
+    const ONES: Place = Place::new(-1).unwrap();
+
+    /// Truncates [`Self`] to a whole number, then rounds its magnitude up (away from zero) if
+    /// `away` is true and anything after the dot was discarded.
+    fn round_away_if(&self, away: bool) -> Self {
+        let truncated = self.trunc();
+
+        let has_fraction = self.digits[self.dot..]
+            .iter()
+            .any(|&digit| digit != Digit::Zero);
+        if !away || !has_fraction {
+            return truncated;
+        }
+
+        let mut digits = truncated.digits.into_vec();
+        increment_digits(&mut digits);
+
+        Self {
+            sign: self.sign,
+            dot: digits.len(),
+            digits: digits.into_boxed_slice(),
+            significant_zeros: 0,
+            phantom: PhantomData,
+        }
+    }
+
     /// Converts a digit index (oriented the list of digits, specific to this [`Self`]) to a
     /// generic [`Place`] (oriented around this [`Self`]'s dot).
     ///
@@ -786,6 +1351,315 @@ impl<F: Float> Digits<F> {
             })
     }
 
+    /// Moves the dot of [`Self`] `by` digits, multiplying [`Self`] by `10^by`.
+    ///
+    /// Positive values of `by` move the dot to the right, negative values move it to the left.
+    /// Zeros are padded onto either end of the digits as necessary to keep the dot within the
+    /// digits. Like [`Self::new`], this keeps a single leading zero before the dot for values less
+    /// than one and drops any other leading zeros before the dot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDigitsPartsError::OutOfBoundsDot`] if the new position of the dot cannot
+    /// be represented (if `self.dot + by` overflows).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// let digits = Digits::<f64>::new(&10.25);
+    ///
+    /// assert_eq!(digits.shift_dot(1).unwrap().to_string(), "102.5");
+    /// assert_eq!(digits.shift_dot(4).unwrap().to_string(), "102500");
+    /// assert_eq!(digits.shift_dot(-1).unwrap().to_string(), "1.025");
+    /// assert_eq!(digits.shift_dot(-3).unwrap().to_string(), "0.01025");
+    /// assert_eq!(digits.shift_dot(0).unwrap(), digits);
+    ///
+    /// assert!(digits.shift_dot(isize::MAX).is_err());
+    /// ```
+    pub fn shift_dot(&self, by: isize) -> Result<Self, InvalidDigitsPartsError> {
+        #[expect(
+            clippy::cast_possible_wrap,
+            reason = "I've never seen the number of digits in an `f64` surpass `i32::MAX`"
+        )]
+        let dot = (self.dot as isize)
+            .checked_add(by)
+            .ok_or(InvalidDigitsPartsError::OutOfBoundsDot)?;
+
+        let mut digits = self.digits.to_vec();
+
+        // Moving the dot to the left of the first digit, so pad with leading zeros such that there
+        // is exactly one zero before the dot.
+        //
+        // ```txt
+        // 10.25    `self`, `self.dot = 2`
+        // ^        `dot = -1`
+        // 0.01025  After padding, `dot = 1`
+        // ```
+        let dot = if dot < 1 {
+            let mut padded = [Digit::Zero].repeat(dot.unsigned_abs() + 1);
+            padded.append(&mut digits);
+            digits = padded;
+
+            1
+        } else {
+            dot.unsigned_abs()
+        };
+
+        // Moving the dot to the right of the last digit, so pad with trailing zeros.
+        //
+        // ```txt
+        // 10.25    `self`, `self.dot = 2`
+        //       ^  `dot = 6`
+        // 102500   After padding
+        // ```
+        if dot > digits.len() {
+            digits.append(&mut [Digit::Zero].repeat(dot - digits.len()));
+        }
+
+        // Trim any leading zeros that were moved to the left of the dot.
+        //
+        // ```txt
+        // 0.0105   `self`, `self.dot = 1`
+        // 010.5    `dot = 3`
+        // 10.5     After trimming, `dot = 2`
+        // ```
+        let leading_zeros = digits[..dot - 1]
+            .iter()
+            .take_while(|&&digit| digit == Digit::Zero)
+            .count();
+
+        Self::from_parts(
+            self.sign,
+            dot - leading_zeros,
+            digits[leading_zeros..].to_vec().into_boxed_slice(),
+        )
+    }
+
+    /// Wraps [`Self`] in a [`DisplayRounded`], which displays [`Self`] rounded to a [`Place`] or a
+    /// number of significant figures.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::num::NonZeroUsize;
+    /// #
+    /// # use sciutil::rounding::digits::{Digits, Place};
+    /// #
+    /// let value = Digits::<f64>::new(&0.015_555);
+    ///
+    /// assert_eq!(format!("{} s", value.rounded(Place::new(3).unwrap())), "0.016 s");
+    /// assert_eq!(
+    ///     format!("{} s", value.rounded(NonZeroUsize::new(3).unwrap())),
+    ///     "0.0156 s",
+    /// );
+    ///
+    /// // `value` itself is unchanged.
+    /// assert_eq!(value.to_string(), "0.015555");
+    /// ```
+    #[must_use]
+    pub fn rounded(&self, round_to: impl Into<RoundTo>) -> DisplayRounded<'_, F> {
+        DisplayRounded::new(self, round_to.into())
+    }
+
+    /// Formats [`Self`] like its [`Display`] implementation, but with the symbol of the physical
+    /// unit of `F` appended, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::{
+    /// #     rounding::digits::Digits,
+    /// #     units::{Float, Seconds, composition::Valued},
+    /// # };
+    /// #
+    /// assert_eq!(
+    ///     Digits::<Valued<f64, Seconds>>::new(&Valued::new(1024.05)).to_string_with_units(),
+    ///     "1024.05 s",
+    /// );
+    ///
+    /// // Unit-less values are displayed as normal.
+    /// assert_eq!(Digits::<f64>::new(&1024.05).to_string_with_units(), "1024.05");
+    /// ```
+    #[must_use]
+    pub fn to_string_with_units(&self) -> String
+    where
+        F: FloatDisplay,
+    {
+        F::symbol().map_or_else(|| self.to_string(), |symbol| format!("{self} {symbol}"))
+    }
+
+    /// Returns a copy of [`Self`] in a canonical form, with any insignificant zeros removed.
+    ///
+    /// Trailing zeros after the dot and leading zeros before the dot are removed, except for a
+    /// single zero before the dot in values less than one (`0.5`, not `.5`). Negative zero becomes
+    /// positive zero, and any [significant zeros](Self::with_significant_figures) are forgotten.
+    ///
+    /// As a result, [`PartialEq`] on normalized forms matches numeric equality: two [`Self`]s
+    /// represent the same number if and only if their normalized forms are equal, see
+    /// [`Self::eq_value`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::{Digit, Digits, Sign};
+    /// #
+    /// let one_point_five_zero = Digits::<f64>::from_parts(
+    ///     Sign::Positive,
+    ///     1,
+    ///     [Digit::One, Digit::Five, Digit::Zero].into(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(one_point_five_zero.to_string(), "1.50");
+    /// assert_eq!(one_point_five_zero.normalized().to_string(), "1.5");
+    /// ```
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        if self.digits.iter().all(|&digit| digit == Digit::Zero) {
+            return Self {
+                sign: Sign::Positive,
+                dot: 1,
+                digits: [Digit::Zero].into(),
+                significant_zeros: 0,
+                phantom: PhantomData,
+            };
+        }
+
+        let trailing_zeros = self.digits[self.dot.min(self.digits.len())..]
+            .iter()
+            .rev()
+            .take_while(|&&digit| digit == Digit::Zero)
+            .count();
+        let leading_zeros = self.digits[..self.dot.saturating_sub(1)]
+            .iter()
+            .take_while(|&&digit| digit == Digit::Zero)
+            .count();
+
+        let digits = &self.digits[leading_zeros..self.digits.len() - trailing_zeros];
+
+        // Values less than one keep a single zero before the dot (`.5` becomes `0.5`).
+        if self.dot == 0 {
+            return Self {
+                sign: self.sign,
+                dot: 1,
+                digits: std::iter::once(Digit::Zero)
+                    .chain(digits.iter().copied())
+                    .collect(),
+                significant_zeros: 0,
+                phantom: PhantomData,
+            };
+        }
+
+        Self {
+            sign: self.sign,
+            dot: self.dot - leading_zeros,
+            digits: digits.into(),
+            significant_zeros: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Whether [`Self`] and `other` represent the same number, even if they are written
+    /// differently (e.g., `1.5` and `1.50`).
+    ///
+    /// This differs from the [`PartialEq`] implementation for [`Self`], which compares the
+    /// representations literally. For hashing consistent with this, see [`NumericHash`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::{Digit, Digits, Sign};
+    /// #
+    /// let one_point_five = Digits::<f64>::new(&1.5);
+    /// let one_point_five_zero = Digits::<f64>::from_parts(
+    ///     Sign::Positive,
+    ///     1,
+    ///     [Digit::One, Digit::Five, Digit::Zero].into(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_ne!(one_point_five, one_point_five_zero);
+    /// assert!(one_point_five.eq_value(&one_point_five_zero));
+    /// assert!(Digits::<f64>::new(&0.0).eq_value(&Digits::new(&-0.0)));
+    /// ```
+    #[must_use]
+    pub fn eq_value(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+
+    /// Reconstructs [`Self`] as an [`f64`], the nearest representable value to the number that
+    /// [`Self`] represents.
+    ///
+    /// This formats [`Self`] and parses the result, which costs a [`String`] allocation. That is
+    /// deliberate: finding the nearest [`f64`] to a decimal with arbitrarily many digits takes
+    /// arbitrary precision arithmetic, which the standard library's parser already implements.
+    /// Accumulating place values in an [`f64`] instead would round at every step and could overflow
+    /// partway through.
+    ///
+    /// The sign is kept, even for zero. [`Digits<F>`] can also be converted [`Into`] an [`f64`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::{Digits, Place};
+    /// #
+    /// assert_eq!(Digits::<f64>::new(&1024.05).to_f64(), 1024.05);
+    /// assert_eq!(f64::from(Digits::<f64>::new(&1024.05)), 1024.05);
+    ///
+    /// let rounded = Digits::<f64>::new(&0.015_555_312).round_to_place(Place::new(3).unwrap());
+    /// assert_eq!(rounded.to_f64(), 0.016);
+    ///
+    /// // Negative zero stays negative.
+    /// assert!(Digits::<f64>::new(&-0.0).to_f64().is_sign_negative());
+    ///
+    /// // Many digits don't overflow.
+    /// assert_eq!(Digits::<f64>::new(&f64::MAX).to_f64(), f64::MAX);
+    /// ```
+    #[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        self.to_string()
+            .parse::<f64>()
+            .expect("`Display` output is always parseable as an `f64`")
+    }
+
+    /// Reconstructs [`Self`] as an [`f32`].
+    ///
+    /// [`f32`] has far less precision and range than the [`f64`] values that [`Self`] is usually
+    /// constructed from, so this is lossy:
+    ///
+    /// - Values are rounded to the nearest representable [`f32`] (about 7 significant figures).
+    /// - Magnitudes beyond [`f32::MAX`] saturate to [`f32::MAX`] (or [`f32::MIN`]), rather than
+    ///   becoming infinite.
+    /// - Magnitudes too small for [`f32`] underflow to zero (keeping their sign).
+    ///
+    /// Like [`Self::to_f64`], this formats [`Self`] and parses the result, but it parses directly
+    /// into an [`f32`]. Converting the result of [`Self::to_f64`] instead would round twice, which
+    /// can land on a different [`f32`] than the one nearest to [`Self`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::rounding::digits::Digits;
+    /// #
+    /// assert_eq!(Digits::<f64>::new(&1024.05).to_f32(), 1024.05_f32);
+    /// assert_eq!(Digits::<f64>::new(&-0.25).to_f32(), -0.25_f32);
+    ///
+    /// // Out of range values saturate.
+    /// assert_eq!(Digits::<f64>::new(&1e300).to_f32(), f32::MAX);
+    /// assert_eq!(Digits::<f64>::new(&-1e300).to_f32(), f32::MIN);
+    /// ```
+    #[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
+    #[must_use]
+    pub fn to_f32(&self) -> f32 {
+        self.to_string()
+            .parse::<f32>()
+            .expect("`Display` output is always parseable as an `f32`")
+            .clamp(f32::MIN, f32::MAX)
+    }
+
     /// Cast [`Self`] to a [`Digit<T>`] of some other [`Float`] `T`.
     ///
     /// ```rust
@@ -803,13 +1677,18 @@ impl<F: Float> Digits<F> {
     #[must_use]
     pub fn cast<T: Float>(self) -> Digits<T> {
         let Self {
-            sign, dot, digits, ..
+            sign,
+            dot,
+            digits,
+            significant_zeros,
+            ..
         } = self;
 
         Digits::<T> {
             sign,
             dot,
             digits,
+            significant_zeros,
             phantom: PhantomData,
         }
     }
@@ -845,7 +1724,7 @@ impl<F: Float> TryFrom<f64> for Digits<F> {
             .strip_prefix("-")
             .map_or((Sign::Positive, str.as_str()), |str| (Sign::Negative, str));
 
-        let mut digits: Vec<Digit> = vec![];
+        let mut digits: Vec<Digit> = Vec::with_capacity(str.len());
         let mut dot = None;
 
         for (index, digit) in str.chars().enumerate() {
@@ -862,17 +1741,39 @@ impl<F: Float> TryFrom<f64> for Digits<F> {
             sign,
             dot: dot.unwrap_or(digits.len()),
             digits: digits.into_boxed_slice(),
+            significant_zeros: 0,
             phantom: PhantomData,
         })
     }
 }
 
+/// Adds one to the last digit of `digits`, carrying as far as necessary, like [`DigitSlice::add`]
+/// but in place and without limiting the number of digits.
+///
+/// Returns whether the carry added a new leading digit.
+fn increment_digits(digits: &mut Vec<Digit>) -> bool {
+    let carried = digits.iter_mut().rev().all(|digit| {
+        if *digit == Digit::Nine {
+            *digit = Digit::Zero;
+            true
+        } else {
+            *digit = Digit::try_from(digit.get() + 1)
+                .expect("only `Digit::Nine` overflows when incremented");
+            false
+        }
+    });
+
+    if carried {
+        digits.insert(0, Digit::One);
+    }
+
+    carried
+}
+
 impl<F: Float> Display for Digits<F> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut str = String::with_capacity(self.digits.len());
-
         if matches!(self.sign, Sign::Negative) {
-            str.push('-');
+            f.write_char('-')?;
         }
 
         // Print zero as `"0"`, not `".0"`.
@@ -880,19 +1781,45 @@ impl<F: Float> Display for Digits<F> {
             // Should this be zero or one?
             self.dot == 0
         {
-            str.push('0');
-            return write!(f, "{str}");
+            return f.write_char('0');
+        }
+
+        let ambiguous_zeros = self.ambiguous_zeros();
+
+        // Some, but not all, of the trailing zeros before the dot are significant, so the only way
+        // to show which is with scientific notation.
+        if self.significant_zeros > 0 && self.significant_zeros < ambiguous_zeros {
+            let first_significant_digit = self
+                .digits
+                .iter()
+                .position(|&digit| digit != Digit::Zero)
+                .expect("`ambiguous_zeros` is zero if every digit is zero");
+            let significant_digits = &self.digits[first_significant_digit
+                ..self.digits.len() - ambiguous_zeros + self.significant_zeros];
+
+            f.write_char(significant_digits[0].into())?;
+            f.write_char('.')?;
+            for &digit in &significant_digits[1..] {
+                f.write_char(digit.into())?;
+            }
+
+            return write!(f, "e{}", self.dot - 1 - first_significant_digit);
         }
 
         for (index, &digit) in self.digits.iter().enumerate() {
             if index == self.dot {
-                str.push('.');
+                f.write_char('.')?;
             }
 
-            str.push(digit.into());
+            f.write_char(digit.into())?;
+        }
+
+        // Every trailing zero before the dot is significant, which is shown with a trailing dot.
+        if self.significant_zeros > 0 {
+            f.write_char('.')?;
         }
 
-        write!(f, "{str}")
+        Ok(())
     }
 }
 
@@ -908,11 +1835,18 @@ impl<F: Float> Clone for Digits<F> {
             sign: self.sign,
             dot: self.dot,
             digits: self.digits.clone(),
+            significant_zeros: self.significant_zeros,
             phantom: PhantomData,
         }
     }
 }
 
+impl<F: Float> From<Digits<F>> for f64 {
+    fn from(value: Digits<F>) -> Self {
+        value.to_f64()
+    }
+}
+
 impl<F: Float> Default for Digits<F> {
     fn default() -> Self {
         Self {
@@ -920,6 +1854,7 @@ impl<F: Float> Default for Digits<F> {
             // Should this be zero or one?
             dot: 0,
             digits: [Digit::Zero].to_vec().into_boxed_slice(),
+            significant_zeros: 0,
             phantom: PhantomData,
         }
     }
@@ -929,7 +1864,10 @@ impl<F: Float> Eq for Digits<F> {}
 
 impl<F: Float> PartialEq for Digits<F> {
     fn eq(&self, other: &Self) -> bool {
-        self.sign == other.sign && self.dot == other.dot && self.digits == other.digits
+        self.sign == other.sign
+            && self.dot == other.dot
+            && self.digits == other.digits
+            && self.significant_zeros == other.significant_zeros
     }
 }
 
@@ -943,7 +1881,11 @@ impl<F: Float> Ord for Digits<F> {
         if cmp != Ordering::Equal {
             return cmp;
         }
-        self.digits.cmp(&other.digits)
+        let cmp = self.digits.cmp(&other.digits);
+        if cmp != Ordering::Equal {
+            return cmp;
+        }
+        self.significant_zeros.cmp(&other.significant_zeros)
     }
 }
 
@@ -953,11 +1895,14 @@ impl<F: Float> PartialOrd for Digits<F> {
     }
 }
 
+// This hashes the literal representation, consistent with `PartialEq`. For hashing by numeric
+// value, consistent with `Digits::eq_value`, see `NumericHash`.
 impl<F: Float> std::hash::Hash for Digits<F> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.sign.hash(state);
         self.dot.hash(state);
         self.digits.hash(state);
+        self.significant_zeros.hash(state);
         self.phantom.hash(state);
     }
 }
@@ -968,6 +1913,7 @@ impl<F: Float> std::fmt::Debug for Digits<F> {
             .field("sign", &self.sign)
             .field("dot", &self.dot)
             .field("digits", &self.digits)
+            .field("significant_zeros", &self.significant_zeros)
             .field("phantom", &self.phantom)
             .finish()
     }
@@ -12,9 +12,9 @@ pub mod digits;
 #[cfg(test)]
 mod test;
 
-use digits::Digits;
+use digits::{Digits, DigitsValue, Place};
 
-use crate::units::{Float, UncertainFloat};
+use crate::units::{Float, FloatDisplay, UncertainFloat};
 
 /// Rounds uncertainty to one or two significant figures and rounds the value to the same place,
 /// returning them as a string with a plus minus notation.
@@ -37,7 +37,7 @@ use crate::units::{Float, UncertainFloat};
 ///     "1024.05 ± 0.016",
 /// );
 ///
-/// // Units do not carry through yet:
+/// // Units do not carry through, see `round_with_uncertainty_with_units` for that:
 /// assert_eq!(
 ///     rounding::round_with_uncertainty(&UncertainFloat::new(
 ///         Valued::<f64, Seconds>::new(1_024.051_123_125_5),
@@ -48,6 +48,70 @@ use crate::units::{Float, UncertainFloat};
 /// ```
 #[must_use]
 pub fn round_with_uncertainty<F: Float>(with_uncertainty: &UncertainFloat<F>) -> String {
+    let (value, uncertainty, _) = round_with_uncertainty_parts(with_uncertainty);
+
+    format!("{value} ± {uncertainty}")
+}
+
+/// Rounds like [`round_with_uncertainty`], then appends the symbol of the unit of `F` (if `F` has
+/// one, see [`FloatDisplay`]) after the uncertainty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{
+/// #     rounding,
+/// #     units::{Float, Seconds, UncertainFloat, composition::Valued},
+/// # };
+/// #
+/// assert_eq!(
+///     rounding::round_with_uncertainty_with_units(&UncertainFloat::new(
+///         Valued::<f64, Seconds>::new(1_024.051_123_125_5),
+///         Valued::<f64, Seconds>::new(0.015_555_312),
+///     )),
+///     "1024.051 ± 0.016 s",
+/// );
+///
+/// // Unit-less values are displayed as normal.
+/// assert_eq!(
+///     rounding::round_with_uncertainty_with_units(&UncertainFloat::new(1_024.05, 0.015_555_312)),
+///     "1024.05 ± 0.016",
+/// );
+/// ```
+#[must_use]
+pub fn round_with_uncertainty_with_units<F: FloatDisplay>(
+    with_uncertainty: &UncertainFloat<F>,
+) -> String {
+    let (value, uncertainty, _) = round_with_uncertainty_parts(with_uncertainty);
+
+    format!("{value} ± {}", uncertainty.to_string_with_units())
+}
+
+/// Rounds uncertainty to one or two significant figures and rounds the value to the same place,
+/// returning the rounded value, the rounded uncertainty, and the [`Place`] they were rounded to.
+///
+/// This rounds exactly like [`round_with_uncertainty`], but leaves the formatting up to the caller,
+/// e.g., for putting the value and uncertainty in separate columns of a table.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{
+/// #     rounding::{self, digits::Place},
+/// #     units::UncertainFloat,
+/// # };
+/// #
+/// let (value, uncertainty, place) =
+///     rounding::round_with_uncertainty_parts(&UncertainFloat::new(1_024.05, 0.015_555_312));
+///
+/// assert_eq!(value.to_string(), "1024.05");
+/// assert_eq!(uncertainty.to_string(), "0.016");
+/// assert_eq!(place, Place::new(3).unwrap());
+/// ```
+#[must_use]
+pub fn round_with_uncertainty_parts<F: Float>(
+    with_uncertainty: &UncertainFloat<F>,
+) -> (Digits<F>, Digits<F>, Place) {
     let value = Digits::<F>::new(with_uncertainty.value());
     let uncertainty = Digits::<F>::new(with_uncertainty.uncertainty());
 
@@ -55,5 +119,156 @@ pub fn round_with_uncertainty<F: Float>(with_uncertainty: &UncertainFloat<F>) ->
     let uncertainty = uncertainty.round_to_place(last_place);
     let value = value.round_to_place(last_place);
 
-    format!("{value} ± {uncertainty}")
+    (value, uncertainty, last_place)
+}
+
+/// Rounds each coordinate of a series of `(T, F)` pairs to the given places, returning them as
+/// strings.
+///
+/// Every `T` value is rounded to `t_place` and every `F` value is rounded to `f_place` (see
+/// [`Digits::round_to_place`]), which is convenient for preparing tables of data for a report.
+/// Non-finite values are not rounded, and are formatted as they are by [`DigitsValue`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::rounding::{self, digits::Place};
+/// #
+/// let list = [(0.0, 1.234_56), (0.125, -20.05), (1.0, f64::NAN)];
+/// let tenths = Place::new(1).unwrap();
+/// let hundredths = Place::new(2).unwrap();
+///
+/// assert_eq!(
+///     rounding::round_pairs(&list, tenths, hundredths).as_ref(),
+///     [
+///         ("0".to_string(), "1.23".to_string()),
+///         ("0.1".to_string(), "-20.05".to_string()),
+///         ("1".to_string(), "NaN".to_string()),
+///     ],
+/// );
+/// ```
+#[must_use]
+pub fn round_pairs<T: Float, F: Float>(
+    list: &[(T, F)],
+    t_place: Place,
+    f_place: Place,
+) -> Box<[(String, String)]> {
+    fn round<F: Float>(value: &F, place: Place) -> String {
+        match DigitsValue::new(value) {
+            DigitsValue::Finite(digits) => digits.round_to_place_cow(place).to_string(),
+            non_finite => non_finite.to_string(),
+        }
+    }
+
+    list.iter()
+        .map(|(t, f)| (round(t, t_place), round(f, f_place)))
+        .collect()
+}
+
+/// Finds the coarsest [`Place`] that any of the uncertainties in `list` round to, so that a whole
+/// column of measurements can be rounded to the same place.
+///
+/// Each uncertainty is rounded as in [`round_with_uncertainty`] (see
+/// [`Digits::last_significant_place`]), and the coarsest of those places (e.g., tenths rather than
+/// hundredths) is returned. Rounding every value and uncertainty to this place produces a table
+/// where every row shows the same precision.
+///
+/// # Errors
+///
+/// - Non-finite uncertainties have no place to round to, so they are skipped (see
+///   [`DigitsValue`]).
+/// - Returns the ones place if `list` is empty or none of its uncertainties are finite.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{
+/// #     rounding::{self, digits::Digits},
+/// #     units::UncertainFloat,
+/// # };
+/// #
+/// let column = [
+///     UncertainFloat::new(1_024.05, 0.015_555_312),
+///     UncertainFloat::new(9.81, 0.42),
+/// ];
+///
+/// // The second uncertainty rounds to the tenths place, which is coarser than the hundredths.
+/// let place = rounding::common_uncertainty_place(&column);
+/// assert_eq!(place.get(), 1);
+///
+/// let rounded = column.map(|measurement| {
+///     Digits::<f64>::new(measurement.value()).round_to_place(place).to_string()
+/// });
+/// assert_eq!(rounded, ["1024.0", "9.8"]);
+/// ```
+#[must_use]
+pub fn common_uncertainty_place<F: Float>(list: &[UncertainFloat<F>]) -> Place {
+    list.iter()
+        .filter_map(
+            |measurement| match DigitsValue::new(measurement.uncertainty()) {
+                DigitsValue::Finite(digits) => Some(digits.last_significant_place()),
+                DigitsValue::NaN | DigitsValue::Infinite(_) => None,
+            },
+        )
+        .min()
+        .unwrap_or(const { Place::new(-1).unwrap() })
+}
+
+/// Rounds `value` as coarsely as possible while keeping the rounding error within `relative` of
+/// `value` (e.g., `0.01` for 1%).
+///
+/// This picks the coarsest [`Place`] whose rounding error (at most half of a unit in that place)
+/// is no more than `relative * |value|`, so the number of significant figures kept adapts to
+/// the tolerance. This is useful when the acceptable error scales with the magnitude of the value.
+///
+/// If `value` is zero, or if `relative` is not positive, `value` is returned without rounding. If
+/// `relative * |value|` is so large that it is infinite, every digit is rounded away, returning
+/// zero.
+///
+/// # Panics
+///
+/// Panics if `value` is NaN or infinite, like [`Digits::new`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::rounding;
+/// #
+/// // 1% of 1234.5678 is about 12, so it can be rounded to the tens place.
+/// let one_percent = rounding::round_to_relative_precision(&1_234.567_8, 0.01);
+/// assert_eq!(one_percent.to_string(), "1230");
+///
+/// // 0.1% is about 1.2, so it can only be rounded to the ones place.
+/// let tenth_percent = rounding::round_to_relative_precision(&1_234.567_8, 0.001);
+/// assert_eq!(tenth_percent.to_string(), "1235");
+/// ```
+#[must_use]
+pub fn round_to_relative_precision<F: Float>(value: &F, relative: f64) -> Digits<F> {
+    let digits = Digits::new(value);
+
+    let tolerance = relative * value.get().abs();
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return digits;
+    }
+
+    // Every digit is within an infinite tolerance, so round all of them away.
+    if (2.0 * tolerance).is_infinite() {
+        return Digits::default();
+    }
+
+    // The largest power of ten where half of a unit is still within the tolerance.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the logarithm of a finite `f64` is well within the range of an `isize`"
+    )]
+    let exponent = (2.0 * tolerance).log10().floor() as isize;
+
+    // The ones place (`10^0`) is `-1`, and the tenths place (`10^-1`) is `1`.
+    let place = if exponent >= 0 {
+        -(exponent + 1)
+    } else {
+        -exponent
+    };
+
+    digits.round_to_place(Place::new(place).expect("`place` is never zero"))
 }
@@ -10,10 +10,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     err::InvalidDigitError,
-    units::{Float, Seconds, composition::Valued},
+    units::{Float, Seconds, UncertainFloat, composition::Valued},
 };
 
-use super::digits::{Digit, DigitSlice, Digits, Sign};
+use super::digits::{
+    Digit, DigitSlice, Digits, DigitsValue, InvalidDigitsPartsError, NumericHash, Place, Sign,
+    digits_from_str,
+};
 
 macro_rules! digit {
     ($digit:expr) => {
@@ -387,6 +390,79 @@ fn bench_digit_slice_add() {
     }
 }
 
+/// Values that are already rounded to the hundredths place, as if read from a data file.
+fn short_digits() -> Box<[Digits<f64>]> {
+    (0..1_000)
+        .map(|i| Digits::new(&(f64::from(i) * 1.25)))
+        .collect()
+}
+
+#[ignore = "benchmark, use `cargo bench -- --ignored -- bench_` to run"]
+#[test]
+fn bench_round_to_place() {
+    let list = short_digits();
+    let place = Place::new(2).unwrap();
+
+    for _ in 0..1_000 {
+        for digits in &list {
+            assert_eq!(&digits.round_to_place(place), digits);
+        }
+    }
+}
+
+/// Compare against [`bench_round_to_place`], this should avoid allocating entirely.
+#[ignore = "benchmark, use `cargo bench -- --ignored -- bench_` to run"]
+#[test]
+fn bench_round_to_place_cow() {
+    let list = short_digits();
+    let place = Place::new(2).unwrap();
+
+    for _ in 0..1_000 {
+        for digits in &list {
+            assert!(matches!(
+                digits.round_to_place_cow(place),
+                std::borrow::Cow::Borrowed(_)
+            ));
+        }
+    }
+}
+
+/// Values with more digits than the place they get rounded to, so every call has to round.
+fn long_digits() -> Box<[Digits<f64>]> {
+    (0..1_000)
+        .map(|i| Digits::new(&(f64::from(i) * 1.234_567_89)))
+        .collect()
+}
+
+/// Rounds a batch of values in place, which should only allocate once per call.
+#[ignore = "benchmark, use `cargo bench -- --ignored -- bench_` to run"]
+#[test]
+fn bench_round_to_place_batch() {
+    let list = long_digits();
+    let place = Place::new(2).unwrap();
+
+    for _ in 0..1_000 {
+        for digits in &list {
+            std::hint::black_box(digits.round_to_place(place));
+        }
+    }
+}
+
+/// Like [`bench_round_to_place_batch`], but rounds to the hundreds place, so every call also has
+/// to append trailing zeros. This should still only allocate once per call.
+#[ignore = "benchmark, use `cargo bench -- --ignored -- bench_` to run"]
+#[test]
+fn bench_round_to_place_batch_trailing_zeros() {
+    let list = long_digits();
+    let place = Place::new(-2).unwrap();
+
+    for _ in 0..1_000 {
+        for digits in &list {
+            std::hint::black_box(digits.round_to_place(place));
+        }
+    }
+}
+
 /// Serialize `start` into JSON and check that it serialized into `expected_json`, then deserialize
 /// it into a `T` and check that it deserialized back into `start`.
 ///
@@ -426,4 +502,628 @@ fn digits_de_re_serialize() {
         &Digits::<Valued<f64, Seconds>>::new(&Valued::new(-0.0)),
         r#"{"sign":"Negative","dot":1,"digits":["Zero"]}"#,
     );
+
+    serialize_and_deserialize(
+        &mut String::new(),
+        &Digits::<f64>::new(&100.0)
+            .with_significant_figures(std::num::NonZeroUsize::new(2).unwrap()),
+        r#"{"sign":"Positive","dot":3,"digits":["One","Zero","Zero"],"significant_zeros":1}"#,
+    );
+
+    // More significant zeros than there are trailing zeros before the dot.
+    assert!(
+        serde_json::from_str::<Digits<f64>>(
+            r#"{"sign":"Positive","dot":2,"digits":["One","Zero"],"significant_zeros":2}"#
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn shift_dot() {
+    let digits_102405 = digits!(Positive, 4, [1, 0, 2, 4, 0, 5]);
+    let digits_00155 = digits!(Negative, 1, [0, 0, 1, 5, 5]);
+
+    // Shifting right within the digits.
+    assert_eq!(
+        digits_102405.shift_dot(1),
+        Ok(digits!(Positive, 5, [1, 0, 2, 4, 0, 5]))
+    );
+    // Shifting right into the integer part, padding with trailing zeros.
+    assert_eq!(
+        digits_102405.shift_dot(4),
+        Ok(digits!(Positive, 8, [1, 0, 2, 4, 0, 5, 0, 0]))
+    );
+    // Shifting right past leading zeros trims them.
+    assert_eq!(
+        digits_00155.shift_dot(2),
+        Ok(digits!(Negative, 1, [1, 5, 5]))
+    );
+    assert_eq!(
+        digits_00155.shift_dot(4),
+        Ok(digits!(Negative, 3, [1, 5, 5]))
+    );
+    // Shifting left into a leading-zero fractional part, padding with leading zeros.
+    assert_eq!(
+        digits_102405.shift_dot(-5),
+        Ok(digits!(Positive, 1, [0, 0, 1, 0, 2, 4, 0, 5]))
+    );
+    assert_eq!(
+        digits_00155.shift_dot(-1),
+        Ok(digits!(Negative, 1, [0, 0, 0, 1, 5, 5]))
+    );
+    // Shifting by zero is a no-op.
+    assert_eq!(digits_102405.shift_dot(0), Ok(digits_102405.clone()));
+
+    // The dot cannot move beyond what can be represented.
+    assert_eq!(
+        digits_102405.shift_dot(isize::MAX),
+        Err(InvalidDigitsPartsError::OutOfBoundsDot)
+    );
+}
+
+#[test]
+fn round_to_place_checked() {
+    let place = |place| Place::new(place).unwrap();
+    let digits_102405 = digits!(Positive, 4, [1, 0, 2, 4, 0, 5]);
+    let digits_150 = digits!(Positive, 1, [1, 5, 0]);
+
+    // Rounding at or beyond the last digit is lossless.
+    assert_eq!(
+        digits_102405.round_to_place_checked(place(2)),
+        (digits_102405.clone(), false)
+    );
+    assert_eq!(
+        digits_102405.round_to_place_checked(place(5)),
+        (digits_102405.clone(), false)
+    );
+    // Dropping trailing zeros is lossless.
+    assert_eq!(
+        digits_150.round_to_place_checked(place(1)),
+        (digits!(Positive, 1, [1, 5]), false)
+    );
+
+    // Dropping non-zero digits is lossy.
+    assert_eq!(
+        digits_102405.round_to_place_checked(place(1)),
+        (digits!(Positive, 4, [1, 0, 2, 4, 0]), true)
+    );
+    assert_eq!(
+        digits_102405.round_to_place_checked(place(-3)),
+        (digits!(Positive, 4, [1, 0, 0, 0]), true)
+    );
+    // Even if it rounds to zero.
+    assert_eq!(
+        digits_150.round_to_place_checked(place(-2)),
+        (Digits::default(), true)
+    );
+}
+
+#[test]
+fn truncate_to_place() {
+    let place = |place| Place::new(place).unwrap();
+    let digits_199 = digits!(Positive, 1, [1, 9, 9]);
+    let digits_neg_199 = digits!(Negative, 3, [1, 9, 9]);
+    let digits_102405 = digits!(Positive, 4, [1, 0, 2, 4, 0, 5]);
+
+    // Truncates after the dot without rounding.
+    assert_eq!(
+        digits_199.truncate_to_place(place(1)),
+        digits!(Positive, 1, [1, 9])
+    );
+    assert_eq!(digits_199.truncate_to_place(place(1)).to_string(), "1.9");
+    assert_eq!(
+        digits_199.truncate_to_place(place(-1)),
+        digits!(Positive, 1, [1])
+    );
+    // Truncates before the dot, padding with zeros.
+    assert_eq!(
+        digits_neg_199.truncate_to_place(place(-2)),
+        digits!(Negative, 3, [1, 9, 0])
+    );
+    assert_eq!(
+        digits_neg_199.truncate_to_place(place(-2)).to_string(),
+        "-190"
+    );
+    assert_eq!(
+        digits_102405.truncate_to_place(place(-3)),
+        digits!(Positive, 4, [1, 0, 0, 0])
+    );
+    // Out of range places.
+    assert_eq!(digits_102405.truncate_to_place(place(3)), digits_102405);
+    assert_eq!(
+        digits_102405.truncate_to_place(place(-5)),
+        Digits::default()
+    );
+}
+
+#[test]
+fn digits_value() {
+    assert_eq!(
+        DigitsValue::<f64>::from_f64(1024.05),
+        DigitsValue::Finite(digits!(Positive, 4, [1, 0, 2, 4, 0, 5]))
+    );
+    assert_eq!(
+        DigitsValue::<f64>::from_f64(-0.0),
+        DigitsValue::Finite(digits!(Negative, 1, [0]))
+    );
+    assert_eq!(DigitsValue::<f64>::from_f64(f64::NAN), DigitsValue::NaN);
+    assert_eq!(DigitsValue::<f64>::from_f64(-f64::NAN), DigitsValue::NaN);
+    assert_eq!(
+        DigitsValue::<f64>::from_f64(f64::INFINITY),
+        DigitsValue::Infinite(Sign::Positive)
+    );
+    assert_eq!(
+        DigitsValue::<f64>::from_f64(f64::NEG_INFINITY),
+        DigitsValue::Infinite(Sign::Negative)
+    );
+
+    let tests = [
+        (1024.05, "1024.05"),
+        (-0.03, "-0.03"),
+        (f64::NAN, "NaN"),
+        (f64::INFINITY, "inf"),
+        (f64::NEG_INFINITY, "-inf"),
+    ];
+    for (value, expected) in tests {
+        assert_eq!(
+            DigitsValue::<Valued<f64, Seconds>>::new(&Valued::new(value)).to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn digits_from_string() {
+    assert_eq!(
+        digits_from_str("1024"),
+        Ok([digit!(1), digit!(0), digit!(2), digit!(4)].into())
+    );
+    assert_eq!(digits_from_str(""), Ok([].into()));
+
+    assert_eq!(digits_from_str("10a24"), Err(InvalidDigitError));
+    assert_eq!(digits_from_str("-1024"), Err(InvalidDigitError));
+}
+
+#[test]
+fn round_pairs() {
+    let list = [
+        (Valued::<f64, Seconds>::new(1.04), 1_024.5),
+        (Valued::<f64, Seconds>::new(2.96), 15.0),
+        (Valued::<f64, Seconds>::new(-3.5), f64::NEG_INFINITY),
+    ];
+    let tens = Place::new(-2).unwrap();
+    let tenths = Place::new(1).unwrap();
+
+    let rounded = super::round_pairs(&list, tenths, tens);
+    assert_eq!(
+        rounded.as_ref(),
+        [
+            ("1.0".to_string(), "1020".to_string()),
+            ("3.0".to_string(), "20".to_string()),
+            ("-3.5".to_string(), "-inf".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn leading_trailing_zeros() {
+    let zeros = |value: f64| {
+        let digits = Digits::new(&value);
+        (digits.leading_zeros(), digits.trailing_zeros())
+    };
+
+    assert_eq!(zeros(0.00305), (2, 0));
+    assert_eq!(zeros(30500.0), (0, 2));
+    assert_eq!(zeros(0.5), (0, 0));
+    assert_eq!(zeros(1.0), (0, 0));
+    assert_eq!(zeros(100.0), (0, 2));
+    assert_eq!(zeros(0.0), (0, 0));
+
+    // Rounding can introduce trailing zeros after the dot.
+    let rounded = Digits::<f64>::new(&1.496).round_to_place(Place::new(2).unwrap());
+    assert_eq!(rounded.to_string(), "1.50");
+    assert_eq!((rounded.leading_zeros(), rounded.trailing_zeros()), (0, 1));
+}
+
+#[test]
+fn significant_figures() {
+    let sig_figs = |count| std::num::NonZeroUsize::new(count).unwrap();
+
+    let thousand = Digits::<f64>::new(&1000.0);
+    assert_eq!(thousand.significant_figures(), 1);
+    for count in 1..=6 {
+        let marked = thousand.with_significant_figures(sig_figs(count));
+        assert_eq!(marked.significant_figures(), count, "{marked}");
+        // Marking zeros does not change the number.
+        assert!(marked.eq_value(&thousand));
+        assert!((marked.to_f32() - 1000.0).abs() < f32::EPSILON, "{marked}");
+    }
+    assert_eq!(
+        thousand.with_significant_figures(sig_figs(2)).to_string(),
+        "1.0e3"
+    );
+    assert_eq!(
+        thousand.with_significant_figures(sig_figs(6)).to_string(),
+        "1000.00"
+    );
+
+    // Rounding (and carrying) to fewer significant figures.
+    let rounded = Digits::<f64>::new(&99.6).with_significant_figures(sig_figs(2));
+    assert_eq!(rounded.to_string(), "1.0e2");
+    assert_eq!(rounded.significant_figures(), 2);
+    let rounded = Digits::<f64>::new(&99.6).with_significant_figures(sig_figs(3));
+    assert_eq!(rounded.to_string(), "99.6");
+    let rounded = Digits::<f64>::new(&1234.0).with_significant_figures(sig_figs(2));
+    assert_eq!(rounded.to_string(), "1200");
+    assert_eq!(rounded.significant_figures(), 2);
+
+    // Small values.
+    let small = Digits::<f64>::new(&0.0305).with_significant_figures(sig_figs(4));
+    assert_eq!(small.to_string(), "0.03050");
+    assert_eq!(small.significant_figures(), 4);
+
+    // Rounding a marked value starts over.
+    let marked = thousand.with_significant_figures(sig_figs(3));
+    assert_eq!(
+        marked.round_to_place(Place::new(-3).unwrap()).to_string(),
+        "1000"
+    );
+}
+
+#[test]
+fn round_to_place_carry() {
+    let round = |value: f64, place| {
+        Digits::<f64>::new(&value)
+            .round_to_place(Place::new(place).unwrap())
+            .to_string()
+    };
+
+    assert_eq!(round(9.96, 1), "10.0");
+    assert_eq!(round(99.6, -1), "100");
+    assert_eq!(round(9.6, -1), "10");
+    assert_eq!(round(0.96, 1), "1.0");
+    assert_eq!(round(0.0096, 3), "0.010");
+}
+
+#[test]
+fn round_half_even_exact_tie_only() {
+    let round = |value: f64, place| {
+        Digits::<f64>::new(&value)
+            .round_to_place(Place::new(place).unwrap())
+            .to_string()
+    };
+
+    // Exact ties round to even.
+    assert_eq!(round(1024.05, 1), "1024.0");
+    assert_eq!(round(1024.15, 1), "1024.2");
+    assert_eq!(round(2.5, -1), "2");
+    assert_eq!(round(3.5, -1), "4");
+
+    // Anything past a tie rounds up, even if the digit before it is even.
+    assert_eq!(round(1024.051, 1), "1024.1");
+    assert_eq!(round(1_024.050_000_1, 1), "1024.1");
+    assert_eq!(round(1234.5678, -1), "1235");
+    assert_eq!(round(2.500_01, -1), "3");
+}
+
+#[test]
+fn significant_figure_place() {
+    let sig_figs = |count| std::num::NonZeroUsize::new(count).unwrap();
+    let place = |place| Place::new(place).unwrap();
+    let digits = Digits::<f64>::new(&0.015_555);
+
+    // 0.02
+    assert_eq!(digits.significant_figure_place(sig_figs(1)), place(2));
+    // 0.016
+    assert_eq!(digits.significant_figure_place(sig_figs(2)), place(3));
+    // 0.0156
+    assert_eq!(digits.significant_figure_place(sig_figs(3)), place(4));
+
+    // Agrees with the one-or-two rule where it applies.
+    assert_eq!(
+        digits.significant_figure_place(sig_figs(2)),
+        digits.last_significant_place()
+    );
+    assert_eq!(
+        digits
+            .round_to_place(digits.significant_figure_place(sig_figs(3)))
+            .to_string(),
+        "0.0156"
+    );
+}
+
+#[test]
+fn to_f32() {
+    for value in [0.0, 1.5, -3.25, 1_024.05, 0.000_015_555, 123_456.7] {
+        #[expect(clippy::cast_possible_truncation, reason = "comparing against `f32`")]
+        let expected = value as f32;
+        assert!(
+            (Digits::<f64>::new(&value).to_f32() - expected).abs() <= expected.abs() * f32::EPSILON
+        );
+    }
+    assert!(Digits::<f64>::new(&-0.0).to_f32().is_sign_negative());
+
+    // Saturates beyond the range of `f32`.
+    assert!((Digits::<f64>::new(&1e39).to_f32() - f32::MAX).abs() < f32::EPSILON);
+    assert!((Digits::<f64>::new(&-1e39).to_f32() - f32::MIN).abs() < f32::EPSILON);
+
+    // Underflows to zero.
+    assert!(Digits::<f64>::new(&1e-50).to_f32().abs() < f32::EPSILON);
+}
+
+#[test]
+fn numeric_hash() {
+    use std::hash::{BuildHasher, RandomState};
+
+    let hasher = RandomState::new();
+    let hash = |digits: Digits<f64>| hasher.hash_one(NumericHash::new(digits));
+    let from_parts = |sign, dot, digits: &[u8]| {
+        Digits::<f64>::from_parts(
+            sign,
+            dot,
+            digits.iter().map(|&digit| digit!(digit)).collect(),
+        )
+        .unwrap()
+    };
+
+    let equal_pairs = [
+        // `1.5` and `1.50`.
+        (Digits::new(&1.5), from_parts(Sign::Positive, 1, &[1, 5, 0])),
+        // `10` and `010.00`.
+        (
+            Digits::new(&10.0),
+            from_parts(Sign::Positive, 3, &[0, 1, 0, 0, 0]),
+        ),
+        // `0` and `-0.000`.
+        (
+            Digits::new(&0.0),
+            from_parts(Sign::Negative, 1, &[0, 0, 0, 0]),
+        ),
+        // `0.05` and `00.050`.
+        (
+            Digits::new(&0.05),
+            from_parts(Sign::Positive, 2, &[0, 0, 0, 5, 0]),
+        ),
+    ];
+    for (a, b) in equal_pairs {
+        assert!(a.eq_value(&b), "{a} != {b}");
+        assert_eq!(
+            hash(a.clone()),
+            hash(b.clone()),
+            "{a} and {b} hash differently"
+        );
+        assert_eq!(NumericHash::new(a), NumericHash::new(b));
+    }
+
+    // Trailing zeros in the integer part are significant.
+    let (a, b) = (Digits::new(&1.0), Digits::new(&10.0));
+    assert!(!a.eq_value(&b));
+    assert_ne!(NumericHash::new(a), NumericHash::new(b));
+    assert!(!Digits::<f64>::new(&1.5).eq_value(&Digits::new(&-1.5)));
+}
+
+#[test]
+fn whole_number_rounding() {
+    // `(value, ceil, floor, round, trunc)`
+    let cases = [
+        (1.5, "2", "1", "2", "1"),
+        (-1.5, "-1", "-2", "-2", "-1"),
+        (2.5, "3", "2", "2", "2"),
+        (-2.5, "-2", "-3", "-2", "-2"),
+        (9.75, "10", "9", "10", "9"),
+        (-0.25, "-0", "-1", "-0", "-0"),
+        (42.0, "42", "42", "42", "42"),
+    ];
+
+    for (value, ceil, floor, round, trunc) in cases {
+        let digits = Digits::<f64>::new(&value);
+
+        assert_eq!(digits.ceil().to_string(), ceil, "ceil({value})");
+        assert_eq!(digits.floor().to_string(), floor, "floor({value})");
+        assert_eq!(digits.round().to_string(), round, "round({value})");
+        assert_eq!(digits.trunc().to_string(), trunc, "trunc({value})");
+
+        // Matches `f64` (including the sign of zero), except that ties round to even.
+        let bits = |digits: Digits<f64>| digits.to_f64().to_bits();
+        assert_eq!(bits(digits.ceil()), value.ceil().to_bits());
+        assert_eq!(bits(digits.floor()), value.floor().to_bits());
+        assert_eq!(bits(digits.round()), value.round_ties_even().to_bits());
+        assert_eq!(bits(digits.trunc()), value.trunc().to_bits());
+    }
+}
+
+#[test]
+fn uncertain_float_rounded() {
+    use std::num::NonZeroU32;
+
+    // Two significant figures, as `round_with_uncertainty` uses for a leading `1`.
+    let cases = [
+        UncertainFloat::new(1_024.051_123, 0.015_555_312),
+        UncertainFloat::new(9.876_54, 0.123),
+        UncertainFloat::new(-3_210.0, 14.9),
+    ];
+    let two = NonZeroU32::new(2).unwrap();
+
+    for measurement in cases {
+        let rounded = measurement.rounded(two);
+        let string = super::round_with_uncertainty(&measurement);
+        let (value, uncertainty) = string.split_once(" ± ").unwrap();
+
+        assert_eq!(rounded.value().to_string(), value, "{string}");
+        assert_eq!(rounded.uncertainty().to_string(), uncertainty, "{string}");
+    }
+
+    // Rounding the uncertainty up adds a digit.
+    let rounded = UncertainFloat::new(1.234_56, 0.099_6).rounded(two);
+    assert_eq!(rounded.to_string(), "1.23 ± 0.1");
+
+    // Non-finite values are left alone.
+    let nan = UncertainFloat::new(f64::NAN, 0.5).rounded(two);
+    assert!(nan.value().is_nan());
+    assert_eq!(nan.uncertainty().to_string(), "0.5");
+}
+
+#[test]
+fn round_to_significant_figures() {
+    use std::num::NonZeroUsize;
+
+    let cases = [
+        (0.015_555_312, 1, "0.02"),
+        (0.015_555_312, 2, "0.016"),
+        (0.099_9, 2, "0.10"),
+        (9.99, 2, "10"),
+        (-9.99, 1, "-10"),
+        (999.5, 3, "1000"),
+        (1_024.05, 10, "1024.05"),
+    ];
+
+    for (value, figures, expected) in cases {
+        let digits = Digits::<f64>::new(&value);
+        let figures = NonZeroUsize::new(figures).unwrap();
+
+        let rounded = digits.round_to_significant_figures(figures);
+        assert_eq!(rounded.to_string(), expected, "{value} to {figures}");
+        // `DisplayRounded` agrees, even when rounding carries into a new digit.
+        assert_eq!(
+            digits.rounded(figures).to_string(),
+            expected,
+            "{value} to {figures}"
+        );
+    }
+
+    let zero = Digits::<f64>::new(&0.0).round_to_significant_figures(NonZeroUsize::MIN);
+    assert_eq!(zero, Digits::default());
+}
+
+#[test]
+fn normalized() {
+    let from_parts = |sign, dot, digits: &[u8]| {
+        Digits::<f64>::from_parts(
+            sign,
+            dot,
+            digits.iter().map(|&digit| digit!(digit)).collect(),
+        )
+        .unwrap()
+    };
+
+    let cases = [
+        // `1.5`, `1.50`, and `001.5000`.
+        (
+            Digits::new(&1.5),
+            [
+                from_parts(Sign::Positive, 1, &[1, 5, 0]),
+                from_parts(Sign::Positive, 3, &[0, 0, 1, 5, 0, 0, 0]),
+            ],
+        ),
+        // `0.25`, `.25`, and `000.250`.
+        (
+            Digits::new(&0.25),
+            [
+                from_parts(Sign::Positive, 0, &[2, 5]),
+                from_parts(Sign::Positive, 3, &[0, 0, 0, 2, 5, 0]),
+            ],
+        ),
+        // `0`, `-0`, and `-.000`.
+        (
+            Digits::new(&0.0),
+            [
+                Digits::new(&-0.0),
+                from_parts(Sign::Negative, 0, &[0, 0, 0]),
+            ],
+        ),
+        // `-100`, `-0100.0`, and `-100.` (which has significant zeros).
+        (
+            Digits::new(&-100.0),
+            [
+                from_parts(Sign::Negative, 4, &[0, 1, 0, 0, 0]),
+                Digits::new(&-100.0).with_significant_figures(3.try_into().unwrap()),
+            ],
+        ),
+    ];
+
+    for (canonical, equivalents) in cases {
+        // Values parsed from an `f64` are already in canonical form.
+        assert_eq!(canonical.normalized(), canonical);
+
+        for equivalent in equivalents {
+            assert_eq!(equivalent.normalized(), canonical, "{equivalent}");
+        }
+    }
+
+    // Normalizing is idempotent.
+    let normalized = from_parts(Sign::Positive, 2, &[0, 0, 0, 5, 0]).normalized();
+    assert_eq!(normalized.to_string(), "0.05");
+    assert_eq!(normalized.normalized(), normalized);
+}
+
+#[test]
+fn round_to_relative_precision() {
+    use super::round_to_relative_precision as round;
+
+    let cases = [
+        (9.876_54, 0.01, "9.9"),
+        (9.876_54, 0.001, "9.88"),
+        (-9.876_54, 0.01, "-9.9"),
+        (0.012_345_6, 0.01, "0.0123"),
+        (98_765.4, 0.01, "99000"),
+        (98_765.4, 0.001, "98800"),
+        // No tolerance means no rounding.
+        (1.234_5, 0.0, "1.2345"),
+        (0.0, 0.01, "0"),
+    ];
+
+    for (value, relative, expected) in cases {
+        let rounded = round(&value, relative);
+        assert_eq!(rounded.to_string(), expected, "{value} within {relative}");
+
+        let rounded = rounded.to_string().parse::<f64>().unwrap();
+        assert!(
+            (rounded - value).abs() <= relative * value.abs(),
+            "{value} within {relative}"
+        );
+    }
+
+    // An infinite tolerance rounds every digit away, rather than overflowing the place.
+    assert_eq!(round(&2.0, f64::INFINITY).to_string(), "0");
+    assert_eq!(round(&-2.0, f64::INFINITY).to_string(), "0");
+    assert_eq!(round(&1e300, 1e10).to_string(), "0");
+    assert_eq!(round(&2.0, 1e300).to_string(), "0");
+}
+
+#[test]
+fn common_uncertainty_place() {
+    let place = |place| Place::new(place).unwrap();
+    let column = [
+        UncertainFloat::new(0.123_456, 0.000_25),
+        UncertainFloat::new(15.0, 3.0),
+        UncertainFloat::new(2.5, 0.012),
+    ];
+
+    // `3` rounds to the ones place, the coarsest of the column.
+    assert_eq!(super::common_uncertainty_place(&column), place(-1));
+    assert_eq!(super::common_uncertainty_place(&column[..1]), place(5));
+    assert_eq!(
+        super::common_uncertainty_place(&[column[0], column[2]]),
+        place(3)
+    );
+
+    // Tens.
+    assert_eq!(
+        super::common_uncertainty_place(&[UncertainFloat::new(1_500.0, 40.0)]),
+        place(-2)
+    );
+
+    assert_eq!(super::common_uncertainty_place::<f64>(&[]), place(-1));
+
+    // Non-finite uncertainties are skipped rather than panicking.
+    let non_finite = [
+        UncertainFloat::new(1.0, f64::NAN),
+        UncertainFloat::new(1.0, f64::INFINITY),
+    ];
+    assert_eq!(super::common_uncertainty_place(&non_finite), place(-1));
+    assert_eq!(
+        super::common_uncertainty_place(&[non_finite[0], column[0], non_finite[1]]),
+        place(5)
+    );
 }
@@ -10,11 +10,30 @@
 //!
 //! This module provides two kinds of numeric derivatives:
 //!
-//! 1. Traditional numeric derivatives: [`first_order`] and [`nth_order`]. These are your typical
-//!    "rise over run" derivatives.
+//! 1. Traditional numeric derivatives: [`first_order`], [`second_order`], and [`nth_order`].
+//!    These are your typical "rise over run" derivatives. [`first_order_per`] types the result as
+//!    a [`Per`], and [`first_order_checked`] checks that the list is
+//!    [sorted][`is_sorted_ascending`] first.
 //!    - Their implementations are also exposed in case you have advanced needs that might benefit
 //!      from differentiating individual points: [`central_difference_derivative`],
 //!      [`forward_difference_derivative`], and [`backward_difference_derivative`].
+//!    - Second-order accurate one-sided differences are also provided for the ends of a list:
+//!      [`forward_difference_second_order`] and [`backward_difference_second_order`]. Or, leave
+//!      the ends out entirely with [`central_differences_interior`].
+//!    - [`first_order_both_uncertain`] propagates uncertainty in both variables into the
+//!      derivative, and [`first_order_with_error`] estimates the error of the derivative itself.
+//!    - Higher-accuracy derivatives for uniformly spaced data: [`first_order_five_point`] and
+//!      [`first_order_richardson`] (and their per-point [`five_point_derivative`] and
+//!      [`richardson_derivative`]). [`spacing_uniformity`] checks whether data is uniform enough
+//!      for them, and [`resample_uniform`] (or [`resample_and_first_order`]) makes it so.
+//!    - [`local_polynomial_derivative`] fits a polynomial to a sliding window of points, smoothing
+//!      out noise in the data. [`total_variation`] can help judge how noisy the data is, and
+//!      [`suggest_smoothing_window`] turns that into a window size, which you can use with
+//!      [`moving_average_pairs`] or [`savitzky_golay`] to smooth the data itself.
+//!    - [`first_order_vec`] differentiates each component of a vector, such as a 2D or 3D
+//!      trajectory, and [`first_order_wrapped`] differentiates periodic values like angles.
+//!    - [`first_order_dedup`] merges points with duplicate `T` values instead of dividing by zero.
+//!    - [`monotonic_segments`] splits data into rising and falling portions at its local extrema.
 //! 2. Time-shifted derivatives: [`first_order_time_shifted`] and [`second_order_time_shifted`].
 //!    These recognize that "rise over run" algorithms don't estimate the derivative at a point, but
 //!    the derivative at the midpoint between it and another point, and does a little bit of
@@ -23,6 +42,16 @@
 //!    - Their implementations are also exposed in case you have advanced needs that might benefit
 //!      from differentiating individual points: [`derivative_time_shifted`] and
 //!      [`second_derivative_time_shifted`].
+//!    - [`first_order_time_shifted_valued`] types its output with a [`Unit`] of your choice.
+//!    - [`curvature`] and [`radius_of_curvature`] describe how sharply the data bends.
+//!
+//! Going the other way, [`trapezoidal`] and [`simpson`] integrate a list of points, and
+//! [`cumulative_trapezoidal`] calculates their running integral. [`integrate_cumulative_typed`]
+//! does the same, typing the result with the product of their units, and
+//! [`integrate_cumulative_per`] integrates a [`Per`] back into its numerator.
+//!
+//! If you'd rather pick an algorithm at runtime, [`differentiate`] dispatches to the first-order
+//! derivatives of both kinds according to a [`DerivativeMethod`].
 //!
 //! For details on the math behind these algorithms, see the Typst document
 //! `/docs/derivatives.typ`. It also provides a Taylor Series expansion of the traditional numeric
@@ -31,9 +60,18 @@
 #[cfg(test)]
 mod test;
 
-use crate::{err::OutOfBoundsIndexError, units::Float};
+use crate::{
+    err::{OutOfBoundsIndexError, UnsortedError},
+    units::{
+        Float, Per, UncertainFloat, Unit,
+        composition::{UnitList, UnitListNull, Valued},
+    },
+};
 
-use std::num::NonZeroU32;
+use std::{cmp::Ordering, num::NonZeroU32};
+
+#[cfg(any(feature = "serde", test))]
+use serde::{Deserialize, Serialize};
 
 /// Perform an operation that returns [`Option`], cast [`None`] to [`OutOfBoundsIndexError`], and
 /// attempt to return on an error.
@@ -50,6 +88,9 @@ macro_rules! oob {
 /// See [`first_order`] for a little more information, or the Typst document
 /// `/docs/derivatives.typ` for details.
 ///
+/// This is only first-order accurate: its error shrinks linearly with the spacing of `T`. If the
+/// first item dominates the error of your results, see [`forward_difference_second_order`].
+///
 /// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
 /// # Errors
@@ -59,10 +100,8 @@ macro_rules! oob {
 ///
 /// # Units
 ///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, 1>`. This function only doesn't return that because it would corner the consumer
-/// into providing the order at compile time.
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 1>`][`Per`]. Wrap it with
+/// [`Float::new`] to get one, or see [`first_order_per`] for a whole list.
 ///
 /// # Examples
 ///
@@ -105,6 +144,9 @@ pub fn forward_difference_derivative<T: Float, F: Float>(
 /// See [`first_order`] for a little more information, or the Typst document
 /// `/docs/derivatives.typ` for details.
 ///
+/// This is only first-order accurate: its error shrinks linearly with the spacing of `T`. If the
+/// last item dominates the error of your results, see [`backward_difference_second_order`].
+///
 /// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
 /// # Errors
@@ -114,10 +156,8 @@ pub fn forward_difference_derivative<T: Float, F: Float>(
 ///
 /// # Units
 ///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, 1>`. This function only doesn't return that because it would corner the consumer
-/// into providing the order at compile time.
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 1>`][`Per`]. Wrap it with
+/// [`Float::new`] to get one, or see [`first_order_per`] for a whole list.
 ///
 /// # Examples
 ///
@@ -171,10 +211,8 @@ pub fn backward_difference_derivative<T: Float, F: Float>(
 ///
 /// # Units
 ///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, 1>`. This function only doesn't return that because it would corner the consumer
-/// into providing the order at compile time.
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 1>`][`Per`]. Wrap it with
+/// [`Float::new`] to get one, or see [`first_order_per`] for a whole list.
 ///
 /// # Examples
 ///
@@ -212,6 +250,164 @@ pub fn central_difference_derivative<T: Float, F: Float>(
     ))
 }
 
+/// Calculates the three-point forward difference derivative. Returns `T` at `index` and the
+/// derivative of `F` over `T` at `index`, using the points at `index`, `index + 1`, and
+/// `index + 2`.
+///
+/// Unlike [`forward_difference_derivative`], this is second-order accurate, so it is much better
+/// suited to the first item of a list where the two-point forward difference dominates the error.
+/// It accounts for non-uniform spacing of `T` values.
+///
+/// Here's the math written out as a Typst expression, where `h_1 = t_2 - t_1` and
+/// `h_2 = t_3 - t_2`:
+///
+/// ```typst
+/// $f'_1 = -(2 h_1 + h_2) / (h_1 (h_1 + h_2)) f_1
+///     + (h_1 + h_2) / (h_1 h_2) f_2
+///     - h_1 / (h_2 (h_1 + h_2)) f_3$
+/// ```
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - Returns [`OutOfBoundsIndexError`] if `index`, `index + 1`, or `index + 2` is out of bounds in
+///   `list`.
+/// - Overlapping `T` values will return an infinite or [`f64::NAN`] derivative.
+///
+/// # Units
+///
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives::{
+/// #     forward_difference_derivative, forward_difference_second_order,
+/// # };
+/// #
+/// // `t^2`, sampled unevenly.
+/// let list = &[(1.0, 1.0), (2.0, 4.0), (4.0, 16.0)];
+///
+/// // The two-point forward difference is off by quite a bit.
+/// assert_eq!(forward_difference_derivative(0, list), Ok((1.0, 3.0)));
+/// // The three-point forward difference is exact for quadratics.
+/// let (independent, derivative) = forward_difference_second_order(0, list).unwrap();
+/// assert_eq!(independent, 1.0);
+/// assert!((derivative - 2.0).abs() < 0.000_000_000_1);
+///
+/// // Overlapping values should cause non-finite derivatives:
+/// let (independent, derivative) =
+///     forward_difference_second_order(0, &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)]).unwrap();
+///
+/// assert_eq!(independent, 1.0);
+/// assert!(!derivative.is_finite());
+/// ```
+pub fn forward_difference_second_order<T: Float, F: Float>(
+    index: usize,
+    list: &[(T, F)],
+) -> Result<(T, f64), OutOfBoundsIndexError> {
+    let (t_1, f_1) = oob!(list.get(index));
+    let (t_2, f_2) = oob!(list.get(index + 1));
+    let (t_3, f_3) = oob!(list.get(index + 2));
+
+    let (t_1, f_1) = (t_1.get(), f_1.get());
+    let (t_2, f_2) = (t_2.get(), f_2.get());
+    let (t_3, f_3) = (t_3.get(), f_3.get());
+
+    let h_1 = t_2 - t_1;
+    let h_2 = t_3 - t_2;
+    let h_13 = h_1 + h_2;
+
+    let weight_1 = -2.0f64.mul_add(h_1, h_2) / (h_1 * h_13);
+    let weight_2 = h_13 / (h_1 * h_2);
+    let weight_3 = -h_1 / (h_2 * h_13);
+
+    Ok((
+        T::new(t_1),
+        weight_1.mul_add(f_1, weight_2.mul_add(f_2, weight_3 * f_3)),
+    ))
+}
+
+/// Calculates the three-point backward difference derivative. Returns `T` at `index` and the
+/// derivative of `F` over `T` at `index`, using the points at `index - 2`, `index - 1`, and
+/// `index`.
+///
+/// Unlike [`backward_difference_derivative`], this is second-order accurate, so it is much better
+/// suited to the last item of a list where the two-point backward difference dominates the error.
+/// It accounts for non-uniform spacing of `T` values.
+///
+/// Here's the math written out as a Typst expression, where `h_1 = t_3 - t_2` and
+/// `h_2 = t_2 - t_1`:
+///
+/// ```typst
+/// $f'_3 = (2 h_1 + h_2) / (h_1 (h_1 + h_2)) f_3
+///     - (h_1 + h_2) / (h_1 h_2) f_2
+///     + h_1 / (h_2 (h_1 + h_2)) f_1$
+/// ```
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - Returns [`OutOfBoundsIndexError`] if `index - 2`, `index - 1`, or `index` is out of bounds in
+///   `list`.
+/// - Overlapping `T` values will return an infinite or [`f64::NAN`] derivative.
+///
+/// # Units
+///
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives::{
+/// #     backward_difference_derivative, backward_difference_second_order,
+/// # };
+/// #
+/// // `t^2`, sampled unevenly.
+/// let list = &[(1.0, 1.0), (3.0, 9.0), (4.0, 16.0)];
+///
+/// // The two-point backward difference is off by quite a bit.
+/// assert_eq!(backward_difference_derivative(2, list), Ok((4.0, 7.0)));
+/// // The three-point backward difference is exact for quadratics.
+/// let (independent, derivative) = backward_difference_second_order(2, list).unwrap();
+/// assert_eq!(independent, 4.0);
+/// assert!((derivative - 8.0).abs() < 0.000_000_000_1);
+///
+/// // Overlapping values should cause non-finite derivatives:
+/// let (independent, derivative) =
+///     backward_difference_second_order(2, &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)]).unwrap();
+///
+/// assert_eq!(independent, 1.0);
+/// assert!(!derivative.is_finite());
+/// ```
+pub fn backward_difference_second_order<T: Float, F: Float>(
+    index: usize,
+    list: &[(T, F)],
+) -> Result<(T, f64), OutOfBoundsIndexError> {
+    let (t_1, f_1) = oob!(list.get(oob!(index.checked_sub(2))));
+    let (t_2, f_2) = oob!(list.get(index - 1));
+    let (t_3, f_3) = oob!(list.get(index));
+
+    let (t_1, f_1) = (t_1.get(), f_1.get());
+    let (t_2, f_2) = (t_2.get(), f_2.get());
+    let (t_3, f_3) = (t_3.get(), f_3.get());
+
+    let h_1 = t_3 - t_2;
+    let h_2 = t_2 - t_1;
+    let h_13 = h_1 + h_2;
+
+    let weight_1 = h_1 / (h_2 * h_13);
+    let weight_2 = -h_13 / (h_1 * h_2);
+    let weight_3 = 2.0f64.mul_add(h_1, h_2) / (h_1 * h_13);
+
+    Ok((
+        T::new(t_3),
+        weight_1.mul_add(f_1, weight_2.mul_add(f_2, weight_3 * f_3)),
+    ))
+}
+
 /// Calculates the numerical derivative of `F` with respect to `T`.
 ///
 /// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
@@ -233,10 +429,8 @@ pub fn central_difference_derivative<T: Float, F: Float>(
 ///
 /// # Units
 ///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, 1>`. This function only doesn't return that because it would corner the consumer
-/// into providing the order at compile time.
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 1>`][`Per`]. [`first_order_per`]
+/// returns the result of this function typed that way.
 ///
 /// # Examples
 ///
@@ -319,251 +513,563 @@ pub fn first_order<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
     derivative.into_boxed_slice()
 }
 
-/// Calculates the nth numerical derivative of `F` with respect to `T`.
-///
-/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+/// Checks whether a list is sorted by ascending (non-decreasing) `T` values, as the functions in
+/// this module assume.
 ///
-/// Note that the first and last data points will be the least accurate, because the
-/// [`central_difference_derivative`] is more accurate than the [`forward_difference_derivative`]
-/// and the [`backward_difference_derivative`]. Unfortunately, the higher the order of the
-/// derivative, the further centrally this error will propagate. For details, see the Typst document
-/// `/docs/derivatives.typ`. It explains the math behind the algorithms and performs the Taylor
-/// Series expansions for a formal explanation of the difference in error for the underlying
-/// algorithms. For a depiction of the difference in accuracy, here is a Desmos graph that
-/// reimplements this function to the second order:
+/// Repeated `T` values are allowed, but any NaN `T` value makes the list unsorted.
 ///
-/// <https://www.desmos.com/calculator/e2k5vughza>
+/// # Examples
 ///
-/// # Errors
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// assert!(derivatives::is_sorted_ascending(&[(0.0, 5.0), (1.0, 2.0), (1.0, 3.0)]));
+/// assert!(!derivatives::is_sorted_ascending(&[(0.0, 5.0), (2.0, 2.0), (1.0, 3.0)]));
+/// assert!(!derivatives::is_sorted_ascending(&[(0.0, 5.0), (f64::NAN, 2.0)]));
+/// assert!(derivatives::is_sorted_ascending::<f64, f64>(&[]));
+/// ```
+#[must_use]
+pub fn is_sorted_ascending<T: Float, F: Float>(list: &[(T, F)]) -> bool {
+    first_unsorted_index(list).is_none()
+}
+
+/// Returns the index of the first `T` value in `list` that is not greater than or equal to the one
+/// before it, or [`None`] if `list` is sorted by ascending `T` values.
+fn first_unsorted_index<T: Float, F: Float>(list: &[(T, F)]) -> Option<usize> {
+    list.windows(2)
+        .position(|window| {
+            window[0]
+                .0
+                .get()
+                .partial_cmp(&window[1].0.get())
+                .is_none_or(Ordering::is_gt)
+        })
+        .map(|index| index + 1)
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` like [`first_order`], after
+/// checking that `list` is sorted by ascending `T` values (see [`is_sorted_ascending`]).
 ///
-/// - `list.len() < 2` returns an empty list.
-/// - Overlapping `T` values will return unusual values.
-///   - First-order derivatives will return [`f64::INFINITY`] as their derivative.
-///   - Higher-order derivatives return a [`f64::NAN`] as their nth derivative.
+/// An unsorted list (such as from a GPS track whose timestamps jump backwards) would otherwise
+/// silently produce meaningless derivatives.
 ///
-/// # Units
+/// # Errors
 ///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, N>`, where `N` is the _nth_ order. This function only doesn't return that because it
-/// would corner the consumer into providing the order at compile time.
+/// - Returns [`UnsortedError`] if `list` is not sorted by ascending `T` values.
+/// - Otherwise, the same as [`first_order`].
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use sciutil::statistics::derivatives;
-/// #
-/// # use std::num::NonZeroU32;
+/// # use sciutil::{err::UnsortedError, statistics::derivatives};
 /// #
-/// // `sin(t)` from `t = 0` to `t = 2`.
-/// let list = (0..=10)
-///     .map(|i| {
-///         let t = f64::from(i) * 0.2;
-///         (t, t.sin())
-///     })
-///     .collect::<Box<_>>();
+/// let sorted = [(0.0, 0.0), (1.0, 2.0), (2.0, 4.0)];
+/// assert_eq!(derivatives::first_order_checked(&sorted), Ok(derivatives::first_order(&sorted)));
 ///
-/// // `-sin(t)` (because `d^2/dx^2 sin(t) = -sin(t)`) from `t = 0` to `t = 2`.
-/// let actual = list.iter().map(|&(t, f)| (t, -f)).collect::<Box<_>>();
-/// assert_eq!(actual.len(), list.len());
+/// let unsorted = [(0.0, 0.0), (2.0, 2.0), (1.0, 4.0)];
+/// assert_eq!(derivatives::first_order_checked(&unsorted), Err(UnsortedError { index: 2 }));
+/// ```
+pub fn first_order_checked<T: Float, F: Float>(
+    list: &[(T, F)],
+) -> Result<Box<[(T, f64)]>, UnsortedError> {
+    if let Some(index) = first_unsorted_index(list) {
+        return Err(UnsortedError { index });
+    }
+
+    Ok(first_order(list))
+}
+
+/// Calculates the numerical second derivative of `F` with respect to `T`.
 ///
-/// let second_order = NonZeroU32::new(2).expect("`2 > 0`");
-/// // The numerical second derivative.
-/// let result = derivatives::nth_order(second_order, &list);
-/// assert_eq!(result.len(), actual.len());
+/// Interior points use the central second difference, which for uniform spacing `h` is
+/// `(f[i - 1] - 2 f[i] + f[i + 1]) / h^2`. The first and last points use second-order accurate
+/// one-sided differences over four points, which for uniform spacing are
+/// `(2 f[0] - 5 f[1] + 4 f[2] - f[3]) / h^2` (and its mirror image). Non-uniform spacing is handled
+/// by taking the second derivative of the polynomial through the same points.
 ///
-/// dbg!(&actual, &result);
-/// dbg!(derivatives::first_order(&list));
+/// This is more accurate than [`nth_order`] with an order of two, which chains two passes of
+/// [`first_order`] and compounds its one-sided error at the ends.
 ///
-/// // Your data may have more or less error than this!
-/// let mut accepted_error = 0.1;
-/// // Assert that the two values are within `accepted_error` of each other.
-/// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
-/// for i in 0..result.len() {
-///     // The last two points have significantly higher error, unfortunately.
-///     if i == result.len() - 2 {
-///         accepted_error = 0.5;
-///     }
+/// # Errors
 ///
-///     let (t, derivative) = actual[i];
-///     let (result_t, result_derivative) = result[i];
+/// - `list.len() < 3` returns an empty list.
+/// - With exactly three points, the ends use the same three points as the interior point, which
+///   is only first-order accurate.
+/// - Overlapping `T` values will return non-finite values as their second derivative.
 ///
-///     // The independent variables should remain unchanged.
-///     eq(t, 0.000_000_000_1, result_t, t);
-///     // The derivatives should be within `accepted_error`.
-///     eq(t, accepted_error, result_derivative, derivative);
-/// }
-/// ```
+/// # Units
 ///
-/// Overlapping values cause infinite or NaN nth derivatives:
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 2>`][`Per`]. Wrap it with
+/// [`Float::new`] to get one, like [`first_order_per`] does for the first order.
+///
+/// # Examples
 ///
 /// ```rust
-/// # use sciutil::statistics::derivatives;
-/// #
 /// # use std::num::NonZeroU32;
 /// #
-/// let list = &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)];
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `sin(t)` from `t = 0` to `t = 2`.
+/// let list = (0..=10)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.2;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
 ///
-/// // Orders greater than one cause NaN.
-/// let result = derivatives::nth_order(NonZeroU32::new(2).unwrap(), list);
-/// assert_eq!(list.len(), result.len());
+/// let result = derivatives::second_order(&list);
+/// assert_eq!(result.len(), list.len());
 ///
-/// for (independent, derivative) in result {
-///     assert_eq!(independent, 1.0);
-///     assert!(derivative.is_nan());
+/// // `nth_order(2)` needs an accepted error of `0.5` near the ends, this is much tighter.
+/// for &(t, derivative) in &result {
+///     assert!((derivative - -t.sin()).abs() < 0.05, "{derivative} != {} @ {t}", -t.sin());
 /// }
 ///
-/// // First derivatives cause infinity.
-/// let result = derivatives::nth_order(NonZeroU32::new(1).unwrap(), list);
-/// assert_eq!(list.len(), result.len());
-///
-/// for (independent, derivative) in result {
-///     assert_eq!(independent, 1.0);
-///     assert!(derivative.is_infinite());
-/// }
+/// // The ends are much more accurate than chaining `first_order` twice.
+/// let chained = derivatives::nth_order(NonZeroU32::new(2).unwrap(), &list);
+/// let last = list.len() - 1;
+/// let error = |derivative: f64| (derivative - -list[last].0.sin()).abs();
+/// assert!(error(result[last].1) < error(chained[last].1));
 /// ```
 #[must_use]
-pub fn nth_order<T: Float, F: Float>(order: NonZeroU32, list: &[(T, F)]) -> Box<[(T, f64)]> {
-    let mut derivative: Box<_> = first_order(list);
-
-    for _ in 2..=order.get() {
-        derivative = first_order(&derivative);
+pub fn second_order<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    if list.len() < 3 {
+        return Box::default();
     }
 
-    derivative
+    let points = list
+        .iter()
+        .map(|(t, f)| (t.get(), f.get()))
+        .collect::<Box<_>>();
+    // Four points for the ends if possible, otherwise the only three there are.
+    let end_width = points.len().min(4);
+
+    (0..points.len())
+        .map(|index| {
+            let stencil = match index {
+                0 => &points[..end_width],
+                index if index == points.len() - 1 => &points[points.len() - end_width..],
+                index => &points[index - 1..=index + 1],
+            };
+
+            (
+                T::new(points[index].0),
+                interpolated_second_derivative(stencil, points[index].0),
+            )
+        })
+        .collect()
 }
 
-/// Calculates the numerical derivative of `F` with respect to `T` at `index` using time-shifted
-/// data points.
+/// Calculates the second derivative at `t` of the polynomial that passes through every point in
+/// `points` (the Lagrange interpolating polynomial).
+///
+/// Each basis polynomial is a product of `points.len() - 1` linear factors `(t - t_m)`, so its
+/// second derivative is twice the sum, over every pair of factors, of the product of the other
+/// factors.
+fn interpolated_second_derivative(points: &[(f64, f64)], t: f64) -> f64 {
+    points
+        .iter()
+        .enumerate()
+        .map(|(j, &(t_j, f_j))| {
+            let others = || {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(m, _)| m != j)
+                    .map(|(m, &(t_m, _))| (m, t_m))
+            };
+
+            let denominator = others().map(|(_, t_m)| t_j - t_m).product::<f64>();
+            let numerator = others()
+                .flat_map(|(k, _)| {
+                    others()
+                        .filter(move |&(l, _)| k < l)
+                        .map(move |(l, _)| (k, l))
+                })
+                .map(|(k, l)| {
+                    others()
+                        .filter(|&(m, _)| m != k && m != l)
+                        .map(|(_, t_m)| t - t_m)
+                        .product::<f64>()
+                })
+                .sum::<f64>();
+
+            2.0 * f_j * numerator / denominator
+        })
+        .sum()
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` like [`first_order`], typing the
+/// result as [`Per<F, T, 1>`][`Per`].
 ///
-/// - Does not include the first or last data points.
-/// - Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
-/// Traditional "rise over run" derivatives calculate the average derivative, at the center of a
-/// time interval. This estimates the derivative at the _start_ of an interval. See
-/// [`central_difference_derivative`] for a more traditional derivative without this time-shifting.
+/// # Errors
 ///
-/// Here's the math written out as a Typst expression, calculating velocity from change in position
-/// over time:
+/// The same as [`first_order`].
 ///
-/// ```typst
-/// $$$
-///     v_2 &= (
-///         v_("avg", 12) Delta t_23
-///         + v_("avg", 23) Delta t_12
-///     ) / (Delta t_13)\
+/// # Examples
 ///
-///     v_2 &= (
-///         (x_2 - x_1) / (t_2 - t_1) (t_3 - t_2)
-///         + (x_3 - x_2) / (t_3 - t_2) (t_2 - t_1)
-///     ) / (t_3 - t_1)\
-/// $$$
+/// ```rust
+/// # use sciutil::{
+/// #     statistics::derivatives,
+/// #     units::{Float, FloatDisplay, Meters, Per, Seconds, composition::Valued},
+/// # };
+/// #
+/// let list = [(0.0, 0.0), (1.0, 2.0), (2.0, 4.0)]
+///     .map(|(t, f)| (Valued::<f64, Seconds>::new(t), Valued::<f64, Meters>::new(f)));
+///
+/// let velocity = derivatives::first_order_per(&list);
+/// assert!(velocity.iter().all(|(_, v)| v.get() == 2.0));
+/// assert_eq!(
+///     Per::<Valued<f64, Meters>, Valued<f64, Seconds>, 1>::symbol().as_deref(),
+///     Some("m / s"),
+/// );
 /// ```
+#[must_use]
+pub fn first_order_per<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, Per<F, T, 1>)]> {
+    first_order(list)
+        .iter()
+        .map(|(t, derivative)| (T::new(t.get()), Per::new(*derivative)))
+        .collect()
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` using
+/// [`central_difference_derivative`] for every interior item, leaving out the first and last
+/// items.
 ///
-/// For details, see the Typst document `/docs/derivatives.typ`. It explains the math further and
-/// derives it. The math is based on William Leonard's article "Dangers of Automated Data
-/// Analysis," pub. _The Physics Teacher,_ vol. 35, April 1996, pp. 220--222.
+/// [`first_order`] fills in the ends with less accurate one-sided differences. If you would rather
+/// not have those at all, this returns exactly `list.len() - 2` points instead.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
 /// # Errors
 ///
-/// - Returns [`OutOfBoundsIndexError`] if `index - 1`, or `index + 1` is out of bounds in `list`.
-/// - Overlapping `T` values will return a [`f64::NAN`] as their derivative.
+/// - `list.len() < 3` returns an empty list.
+/// - Overlapping `T` values will return non-finite values as their derivative.
 ///
 /// # Units
 ///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, 1>`. This function only doesn't return that because it would corner the consumer
-/// into providing the order at compile time.
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use sciutil::statistics::derivatives::derivative_time_shifted;
+/// # use sciutil::statistics::derivatives;
 /// #
-/// // Normal behavior:
-/// let list = &[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
-/// let derivative = ((5.0_f64 - 3.0) / (2.0 - 1.0))
-///     .mul_add(1.0 - 0.0, (3.0 - 1.0) / (1.0 - 0.0) * (2.0 - 1.0))
-///     / (2.0 - 0.0);
+/// // `t^2`, which has a derivative of `2t`.
+/// let list = [0.0, 1.0, 2.0, 3.0].map(|t: f64| (t, t.powi(2)));
 ///
-/// assert_eq!(
-///     derivative_time_shifted(1, list),
-///     Ok((1.0, derivative))
-/// );
-///
-/// // Overlapping values should cause NaN derivatives:
-/// let (independent, derivative) =
-///     derivative_time_shifted(1, &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)]).unwrap();
-/// dbg!(independent, derivative);
-///
-/// assert_eq!(independent, 1.0);
-/// assert!(derivative.is_nan());
+/// assert_eq!(*derivatives::central_differences_interior(&list), [(1.0, 2.0), (2.0, 4.0)]);
+/// assert!(derivatives::central_differences_interior(&list[..2]).is_empty());
 /// ```
-pub fn derivative_time_shifted<T: Float, F: Float>(
-    index: usize,
-    list: &[(T, F)],
-) -> Result<(T, f64), OutOfBoundsIndexError> {
-    let get = |index: usize| {
-        let (t, f) = oob!(list.get(index));
-        Ok((t.get(), f.get()))
-    };
+#[must_use]
+#[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
+pub fn central_differences_interior<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    if list.len() < 3 {
+        return Box::default();
+    }
 
-    let (independent_1, dependent_1) = get(oob!(index.checked_sub(1)))?;
-    let (independent_2, dependent_2) = get(index)?;
-    let (independent_3, dependent_3) = get(index + 1)?;
+    (1..(list.len() - 1))
+        .map(|index| {
+            central_difference_derivative(index, list)
+                .expect("`0 < index < len - 1`, indices `index - 1` and `index + 1` exist")
+        })
+        .collect()
+}
 
-    let delta_independent_12 = independent_2 - independent_1;
-    let delta_dependent_12 = dependent_2 - dependent_1;
-    let derivative_avg_12 = delta_dependent_12 / delta_independent_12;
+/// Calculates the numerical derivative of a periodic quantity `F` (such as an angle) with respect
+/// to `T`, unwrapping jumps across the period boundary before differentiating.
+///
+/// Each consecutive difference in `F` is reduced modulo `period` into `[-period / 2, period / 2)`,
+/// so a value that wraps from just under `period` back to just over zero is treated as continuing
+/// forward rather than jumping backwards. The unwrapped values are then differentiated using
+/// [`first_order`]. This assumes that the true change between consecutive samples is always less
+/// than half of `period`.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - `list.len() < 2` returns an empty list.
+/// - A `period` that is not positive and finite (zero, negative, infinite, or NaN) can't wrap
+///   anything, so the values are differentiated with [`first_order`] as they are.
+/// - Overlapping `T` values will return non-finite values as their derivative.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // An angle in degrees increasing by 100 degrees per second, wrapping at 360 degrees.
+/// let list = (0..10)
+///     .map(|i| {
+///         let t = f64::from(i);
+///         (t, (t * 100.0) % 360.0)
+///     })
+///     .collect::<Box<_>>();
+///
+/// for (_, rate) in derivatives::first_order_wrapped(&list, 360.0) {
+///     assert!((rate - 100.0).abs() < 1e-9);
+/// }
+///
+/// // Without a usable period, nothing is unwrapped.
+/// for period in [0.0, -360.0, f64::INFINITY, f64::NAN] {
+///     assert_eq!(
+///         derivatives::first_order_wrapped(&list, period),
+///         derivatives::first_order(&list),
+///     );
+/// }
+/// ```
+#[must_use]
+pub fn first_order_wrapped<T: Float>(list: &[(T, f64)], period: f64) -> Box<[(T, f64)]> {
+    if !(period.is_finite() && period > 0.0) {
+        return first_order(list);
+    }
 
-    let delta_independent_23 = independent_3 - independent_2;
-    let delta_dependent_23 = dependent_3 - dependent_2;
-    let derivative_avg_23 = delta_dependent_23 / delta_independent_23;
+    let mut unwrapped = Vec::with_capacity(list.len());
+    let mut previous: Option<(f64, f64)> = None;
 
-    let delta_independent_13 = independent_3 - independent_1;
+    for (t, f) in list {
+        let f = *f;
+        let value = match previous {
+            None => f,
+            Some((raw, total)) => {
+                let half = period / 2.0;
+                let difference = (f - raw + half).rem_euclid(period) - half;
+                total + difference
+            }
+        };
 
-    Ok((
-        T::new(independent_2.get()),
-        derivative_avg_12.mul_add(
-            delta_independent_23,
-            derivative_avg_23 * delta_independent_12,
-        ) / delta_independent_13,
-    ))
+        previous = Some((f, value));
+        unwrapped.push((T::new(t.get()), value));
+    }
+
+    first_order(&unwrapped)
 }
 
-/// Calculates the numerical derivative of `F` with respect to `T` using time-shifted data points.
+/// Calculates the numerical derivative of each of the `N` components of a vector with respect to
+/// `T`, such as the velocity of a 2D or 3D trajectory.
 ///
-/// - Does not include the first or last data points.
-/// - Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+/// Each component is differentiated independently using the same difference quotients as
+/// [`first_order`]: a forward difference for the first item, central differences for the middle
+/// items, and a backward difference for the last item. Returns an empty list if `list` has fewer
+/// than two items.
 ///
-/// Traditional "rise over run" derivatives calculate the average derivative, at the center of a
-/// time interval. This estimates the derivative at the _start_ of an interval.
+/// # Examples
 ///
-/// For details, see the Typst document `/docs/derivatives.typ`. It explains the math further and
-/// derives it. The math is based on William Leonard's article "Dangers of Automated Data
-/// Analysis," pub. _The Physics Teacher,_ vol. 35, April 1996, pp. 220--222.
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // A position moving at a constant `(1, -2)` per unit of time.
+/// let list = (0..10)
+///     .map(|i| {
+///         let t = f64::from(i);
+///         (t, [t, 5.0 - 2.0 * t])
+///     })
+///     .collect::<Box<_>>();
+///
+/// for (_, [x, y]) in derivatives::first_order_vec(&list) {
+///     assert!((x - 1.0).abs() < 1e-12);
+///     assert!((y + 2.0).abs() < 1e-12);
+/// }
+/// ```
+#[must_use]
+pub fn first_order_vec<T: Float, const N: usize>(list: &[(T, [f64; N])]) -> Box<[(T, [f64; N])]> {
+    if list.len() < 2 {
+        return Box::default();
+    }
+
+    (0..list.len())
+        .map(|index| {
+            let (a, b) = first_order_indices(index, list.len());
+
+            let (t_a, f_a) = &list[a];
+            let (t_b, f_b) = &list[b];
+            let dt = t_b.get() - t_a.get();
+
+            (
+                T::new(list[index].0.get()),
+                std::array::from_fn(|component| (f_b[component] - f_a[component]) / dt),
+            )
+        })
+        .collect()
+}
+
+/// The indices of the two items that [`first_order`] takes the difference quotient of for the
+/// item at `index` of a list of `len` items: a forward difference for the first item, a backward
+/// difference for the last item, and a central difference otherwise.
+///
+/// Expects `len >= 2` and `index < len`.
+const fn first_order_indices(index: usize, len: usize) -> (usize, usize) {
+    let last_index = len - 1;
+
+    match index {
+        0 => (0, 1),
+        _ if index == last_index => (last_index - 1, last_index),
+        _ => (index - 1, index + 1),
+    }
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` like [`first_order`], but first
+/// collapses consecutive points with equal `T` values into one point, averaging their `F` values.
+///
+/// [`first_order`] divides by zero wherever consecutive `T` values are equal, producing infinite or
+/// NaN derivatives. This is common with sensor data that records duplicate timestamps, so this
+/// function removes the duplicates instead. Because of this, the returned list may be shorter than
+/// `list`, with one item for every distinct run of `T` values.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// let list = &[(0.0, 0.0), (1.0, 2.0), (2.0, 3.0), (2.0, 5.0)];
+///
+/// let result = derivatives::first_order_dedup(list);
+/// assert_eq!(&*result, &[(0.0, 2.0), (1.0, 2.0), (2.0, 2.0)]);
+///
+/// // Compare to:
+/// assert!(derivatives::first_order(list)[3].1.is_infinite());
+/// ```
+#[must_use]
+#[expect(clippy::float_cmp, reason = "only exactly equal values divide by zero")]
+pub fn first_order_dedup<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    let deduplicated = list
+        .chunk_by(|(t_a, _), (t_b, _)| t_a.get() == t_b.get())
+        .map(|run| {
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "I can't think of another way to do this"
+            )]
+            let mean = run.iter().map(|(_, f)| f.get()).sum::<f64>() / run.len() as f64;
+
+            (T::new(run[0].0.get()), mean)
+        })
+        .collect::<Box<_>>();
+
+    first_order(&deduplicated)
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` when both have uncertainties,
+/// propagating both uncertainties into the derivative.
+///
+/// Uses the same difference quotients as [`first_order`]: a forward difference for the first item,
+/// central differences for the middle items, and a backward difference for the last item. For each
+/// difference quotient `(f_b - f_a) / (t_b - t_a)`, the uncertainty is propagated through both the
+/// numerator and the denominator:
+///
+/// ```typst
+/// $delta f' = sqrt(
+///     ((delta f_a^2 + delta f_b^2) / (t_b - t_a)^2)
+///     + f'^2 (delta t_a^2 + delta t_b^2) / (t_b - t_a)^2
+/// )$
+/// ```
+///
+/// This assumes that every uncertainty is independent of every other uncertainty (that they are
+/// uncorrelated). Uncertainties are treated as absolute uncertainties, as in [`UncertainFloat`].
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
 /// # Errors
 ///
-/// - Overlapping `T` values will return a [`f64::NAN`] as their derivative.
-/// - `list.len() < 3` returns an empty list.
+/// - `list.len() < 2` returns an empty list.
+/// - Overlapping `T` values will return non-finite values as their derivative and uncertainty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{statistics::derivatives, units::UncertainFloat};
+/// #
+/// let list = &[
+///     (UncertainFloat::new(0.0, 0.1), UncertainFloat::new(1.0, 0.2)),
+///     (UncertainFloat::new(1.0, 0.1), UncertainFloat::new(3.0, 0.2)),
+/// ];
+///
+/// let result = derivatives::first_order_both_uncertain(list);
+/// assert_eq!(result.len(), list.len());
+///
+/// let (t, derivative) = result[0];
+/// assert_eq!(t, 0.0);
+/// assert_eq!(*derivative.value(), 2.0);
+///
+/// // `sqrt((0.2^2 + 0.2^2) / 1^2 + 2^2 (0.1^2 + 0.1^2) / 1^2)`
+/// let expected = (0.08_f64 + 4.0 * 0.02).sqrt();
+/// assert!((derivative.uncertainty() - expected).abs() < 0.000_000_000_1);
+/// ```
+#[must_use]
+pub fn first_order_both_uncertain(
+    list: &[(UncertainFloat<f64>, UncertainFloat<f64>)],
+) -> Box<[(f64, UncertainFloat<f64>)]> {
+    if list.len() < 2 {
+        return Box::default();
+    }
+
+    let difference_quotient = |a: usize, b: usize| {
+        let (t_a, f_a) = list[a];
+        let (t_b, f_b) = list[b];
+
+        let delta_t = t_b.value() - t_a.value();
+        let derivative = (f_b.value() - f_a.value()) / delta_t;
+
+        // The squared uncertainties of the numerator and denominator of the difference quotient.
+        let numerator_variance = f_a.uncertainty().powi(2) + f_b.uncertainty().powi(2);
+        let denominator_variance = t_a.uncertainty().powi(2) + t_b.uncertainty().powi(2);
+        let uncertainty = derivative
+            .powi(2)
+            .mul_add(denominator_variance, numerator_variance)
+            .sqrt()
+            / delta_t.abs();
+
+        UncertainFloat::new(derivative, uncertainty)
+    };
+
+    (0..list.len())
+        .map(|index| {
+            let (a, b) = first_order_indices(index, list.len());
+
+            (*list[index].0.value(), difference_quotient(a, b))
+        })
+        .collect()
+}
+
+/// Calculates the nth numerical derivative of `F` with respect to `T`.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// Note that the first and last data points will be the least accurate, because the
+/// [`central_difference_derivative`] is more accurate than the [`forward_difference_derivative`]
+/// and the [`backward_difference_derivative`]. Unfortunately, the higher the order of the
+/// derivative, the further centrally this error will propagate. For details, see the Typst document
+/// `/docs/derivatives.typ`. It explains the math behind the algorithms and performs the Taylor
+/// Series expansions for a formal explanation of the difference in error for the underlying
+/// algorithms. For a depiction of the difference in accuracy, here is a Desmos graph that
+/// reimplements this function to the second order:
+///
+/// <https://www.desmos.com/calculator/e2k5vughza>
+///
+/// # Errors
+///
+/// - `list.len() < 2` returns an empty list.
+/// - Overlapping `T` values will return unusual values.
+///   - First-order derivatives will return [`f64::INFINITY`] as their derivative.
+///   - Higher-order derivatives return a [`f64::NAN`] as their nth derivative.
 ///
 /// # Units
 ///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, 1>`. This function only doesn't return that because it would corner the consumer
-/// into providing the order at compile time.
+/// The output [`f64`] is most correctly typed as [`Per<F, T, N>`][`Per`], where `N` is the _nth_
+/// order. [`Per`] takes the order at compile time, so when `order` is known at compile time, wrap
+/// the result with [`Float::new`] like [`first_order_per`] does for the first order.
 ///
 /// # Examples
 ///
-/// Comparing with the actual derivative:
-///
 /// ```rust
 /// # use sciutil::statistics::derivatives;
 /// #
+/// # use std::num::NonZeroU32;
+/// #
 /// // `sin(t)` from `t = 0` to `t = 2`.
 /// let list = (0..=10)
 ///     .map(|i| {
@@ -572,21 +1078,30 @@ pub fn derivative_time_shifted<T: Float, F: Float>(
 ///     })
 ///     .collect::<Box<_>>();
 ///
-/// // `cos(t)` (because `d/dx sin(t) = cos(t)`) from `t = 0` to `t = 2`.
-/// let actual = list.iter().map(|(t, _)| (*t, t.cos())).collect::<Box<_>>();
+/// // `-sin(t)` (because `d^2/dx^2 sin(t) = -sin(t)`) from `t = 0` to `t = 2`.
+/// let actual = list.iter().map(|&(t, f)| (t, -f)).collect::<Box<_>>();
 /// assert_eq!(actual.len(), list.len());
 ///
-/// // The numerical derivative.
-/// let result = derivatives::first_order_time_shifted(&list);
-/// assert_eq!(result.len(), actual.len() - 2);
+/// let second_order = NonZeroU32::new(2).expect("`2 > 0`");
+/// // The numerical second derivative.
+/// let result = derivatives::nth_order(second_order, &list);
+/// assert_eq!(result.len(), actual.len());
+///
+/// dbg!(&actual, &result);
+/// dbg!(derivatives::first_order(&list));
 ///
 /// // Your data may have more or less error than this!
-/// let accepted_error = 0.0075;
+/// let mut accepted_error = 0.1;
 /// // Assert that the two values are within `accepted_error` of each other.
 /// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
 ///
 /// for i in 0..result.len() {
-///     let (t, derivative) = actual[i + 1];
+///     // The last two points have significantly higher error, unfortunately.
+///     if i == result.len() - 2 {
+///         accepted_error = 0.5;
+///     }
+///
+///     let (t, derivative) = actual[i];
 ///     let (result_t, result_derivative) = result[i];
 ///
 ///     // The independent variables should remain unchanged.
@@ -596,326 +1111,1892 @@ pub fn derivative_time_shifted<T: Float, F: Float>(
 /// }
 /// ```
 ///
-/// Comparing it to the values calculated by Vernier's Logger Pro®, version 3.16.2:
+/// Overlapping values cause infinite or NaN nth derivatives:
 ///
 /// ```rust
 /// # use sciutil::statistics::derivatives;
 /// #
-/// const EXPECTED: [(f64, f64); 13] = [
-///     (0.2, 0.973_545_855_772),
-///     (0.4, 0.914_932_856_5),
-///     (0.6, 0.819_844_371_477),
-///     (0.8, 0.692_071_278_532),
-///     (1.0, 0.536_707_487_669),
-///     (1.2, 0.359_946_862_951),
-///     (1.4, 0.168_836_292_686),
-///     (1.6, -0.029_005_247_775_7),
-///     (1.8, -0.225_690_440_54),
-///     (2.0, -0.413_378_067_647),
-///     (2.2, -0.584_585_615_686),
-///     (2.4, -0.732_487_579_995),
-///     (2.6, -0.851_187_575_988),
-/// ];
+/// # use std::num::NonZeroU32;
+/// #
+/// let list = &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)];
 ///
-/// // `sin(t)` from `t = 0` to `t = 2.8`.
-/// let list = (0..15)
-///     .map(|i| {
-///         let t = f64::from(i) * 0.2;
-///         (t, t.sin())
-///     })
-///     .collect::<Box<_>>();
-/// assert_eq!(list.len(), EXPECTED.len() + 2);
+/// // Orders greater than one cause NaN.
+/// let result = derivatives::nth_order(NonZeroU32::new(2).unwrap(), list);
+/// assert_eq!(list.len(), result.len());
 ///
-/// // The numerical derivative.
-/// let result = derivatives::first_order_time_shifted(&list);
-/// assert_eq!(result.len(), EXPECTED.len());
+/// for (independent, derivative) in result {
+///     assert_eq!(independent, 1.0);
+///     assert!(derivative.is_nan());
+/// }
 ///
-/// let effectively_equal = 0.000_000_000_1;
-/// // Assert that the two values are within `error` of each other.
-/// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
+/// // First derivatives cause infinity.
+/// let result = derivatives::nth_order(NonZeroU32::new(1).unwrap(), list);
+/// assert_eq!(list.len(), result.len());
+///
+/// for (independent, derivative) in result {
+///     assert_eq!(independent, 1.0);
+///     assert!(derivative.is_infinite());
+/// }
+/// ```
+#[must_use]
+pub fn nth_order<T: Float, F: Float>(order: NonZeroU32, list: &[(T, F)]) -> Box<[(T, f64)]> {
+    let mut derivative: Box<_> = first_order(list);
+
+    for _ in 2..=order.get() {
+        derivative = first_order(&derivative);
+    }
+
+    derivative
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` at `index` using time-shifted
+/// data points.
+///
+/// - Does not include the first or last data points.
+/// - Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// Traditional "rise over run" derivatives calculate the average derivative, at the center of a
+/// time interval. This estimates the derivative at the _start_ of an interval. See
+/// [`central_difference_derivative`] for a more traditional derivative without this time-shifting.
+///
+/// Here's the math written out as a Typst expression, calculating velocity from change in position
+/// over time:
+///
+/// ```typst
+/// $$$
+///     v_2 &= (
+///         v_("avg", 12) Delta t_23
+///         + v_("avg", 23) Delta t_12
+///     ) / (Delta t_13)\
+///
+///     v_2 &= (
+///         (x_2 - x_1) / (t_2 - t_1) (t_3 - t_2)
+///         + (x_3 - x_2) / (t_3 - t_2) (t_2 - t_1)
+///     ) / (t_3 - t_1)\
+/// $$$
+/// ```
+///
+/// For details, see the Typst document `/docs/derivatives.typ`. It explains the math further and
+/// derives it. The math is based on William Leonard's article "Dangers of Automated Data
+/// Analysis," pub. _The Physics Teacher,_ vol. 35, April 1996, pp. 220--222.
+///
+/// # Errors
+///
+/// - Returns [`OutOfBoundsIndexError`] if `index - 1`, or `index + 1` is out of bounds in `list`.
+/// - Overlapping `T` values will return a [`f64::NAN`] as their derivative.
+///
+/// # Units
+///
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 1>`][`Per`]. Wrap it with
+/// [`Float::new`] to get one, like [`first_order_per`] does.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives::derivative_time_shifted;
+/// #
+/// // Normal behavior:
+/// let list = &[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
+/// let derivative = ((5.0_f64 - 3.0) / (2.0 - 1.0))
+///     .mul_add(1.0 - 0.0, (3.0 - 1.0) / (1.0 - 0.0) * (2.0 - 1.0))
+///     / (2.0 - 0.0);
+///
+/// assert_eq!(
+///     derivative_time_shifted(1, list),
+///     Ok((1.0, derivative))
+/// );
+///
+/// // Overlapping values should cause NaN derivatives:
+/// let (independent, derivative) =
+///     derivative_time_shifted(1, &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)]).unwrap();
+/// dbg!(independent, derivative);
+///
+/// assert_eq!(independent, 1.0);
+/// assert!(derivative.is_nan());
+/// ```
+pub fn derivative_time_shifted<T: Float, F: Float>(
+    index: usize,
+    list: &[(T, F)],
+) -> Result<(T, f64), OutOfBoundsIndexError> {
+    let get = |index: usize| {
+        let (t, f) = oob!(list.get(index));
+        Ok((t.get(), f.get()))
+    };
+
+    let (independent_1, dependent_1) = get(oob!(index.checked_sub(1)))?;
+    let (independent_2, dependent_2) = get(index)?;
+    let (independent_3, dependent_3) = get(index + 1)?;
+
+    let delta_independent_12 = independent_2 - independent_1;
+    let delta_dependent_12 = dependent_2 - dependent_1;
+    let derivative_avg_12 = delta_dependent_12 / delta_independent_12;
+
+    let delta_independent_23 = independent_3 - independent_2;
+    let delta_dependent_23 = dependent_3 - dependent_2;
+    let derivative_avg_23 = delta_dependent_23 / delta_independent_23;
+
+    let delta_independent_13 = independent_3 - independent_1;
+
+    Ok((
+        T::new(independent_2.get()),
+        derivative_avg_12.mul_add(
+            delta_independent_23,
+            derivative_avg_23 * delta_independent_12,
+        ) / delta_independent_13,
+    ))
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` using time-shifted data points.
+///
+/// - Does not include the first or last data points.
+/// - Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// Traditional "rise over run" derivatives calculate the average derivative, at the center of a
+/// time interval. This estimates the derivative at the _start_ of an interval.
+///
+/// For details, see the Typst document `/docs/derivatives.typ`. It explains the math further and
+/// derives it. The math is based on William Leonard's article "Dangers of Automated Data
+/// Analysis," pub. _The Physics Teacher,_ vol. 35, April 1996, pp. 220--222.
+///
+/// # Errors
+///
+/// - Overlapping `T` values will return a [`f64::NAN`] as their derivative.
+/// - `list.len() < 3` returns an empty list.
+///
+/// # Units
+///
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 1>`][`Per`]. Wrap it with
+/// [`Float::new`] to get one, like [`first_order_per`] does.
+///
+/// # Examples
+///
+/// Comparing with the actual derivative:
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `sin(t)` from `t = 0` to `t = 2`.
+/// let list = (0..=10)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.2;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+///
+/// // `cos(t)` (because `d/dx sin(t) = cos(t)`) from `t = 0` to `t = 2`.
+/// let actual = list.iter().map(|(t, _)| (*t, t.cos())).collect::<Box<_>>();
+/// assert_eq!(actual.len(), list.len());
+///
+/// // The numerical derivative.
+/// let result = derivatives::first_order_time_shifted(&list);
+/// assert_eq!(result.len(), actual.len() - 2);
+///
+/// // Your data may have more or less error than this!
+/// let accepted_error = 0.0075;
+/// // Assert that the two values are within `accepted_error` of each other.
+/// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
+///
+/// for i in 0..result.len() {
+///     let (t, derivative) = actual[i + 1];
+///     let (result_t, result_derivative) = result[i];
+///
+///     // The independent variables should remain unchanged.
+///     eq(t, 0.000_000_000_1, result_t, t);
+///     // The derivatives should be within `accepted_error`.
+///     eq(t, accepted_error, result_derivative, derivative);
+/// }
+/// ```
+///
+/// Comparing it to the values calculated by Vernier's Logger Pro®, version 3.16.2:
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// const EXPECTED: [(f64, f64); 13] = [
+///     (0.2, 0.973_545_855_772),
+///     (0.4, 0.914_932_856_5),
+///     (0.6, 0.819_844_371_477),
+///     (0.8, 0.692_071_278_532),
+///     (1.0, 0.536_707_487_669),
+///     (1.2, 0.359_946_862_951),
+///     (1.4, 0.168_836_292_686),
+///     (1.6, -0.029_005_247_775_7),
+///     (1.8, -0.225_690_440_54),
+///     (2.0, -0.413_378_067_647),
+///     (2.2, -0.584_585_615_686),
+///     (2.4, -0.732_487_579_995),
+///     (2.6, -0.851_187_575_988),
+/// ];
+///
+/// // `sin(t)` from `t = 0` to `t = 2.8`.
+/// let list = (0..15)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.2;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+/// assert_eq!(list.len(), EXPECTED.len() + 2);
+///
+/// // The numerical derivative.
+/// let result = derivatives::first_order_time_shifted(&list);
+/// assert_eq!(result.len(), EXPECTED.len());
+///
+/// let effectively_equal = 0.000_000_000_1;
+/// // Assert that the two values are within `error` of each other.
+/// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
+///
+/// for i in 0..result.len() {
+///     let (t, derivative) = EXPECTED[i];
+///     let (result_t, result_derivative) = result[i];
+///
+///     // The independent variables should remain unchanged.
+///     eq(t, effectively_equal, result_t, t);
+///     // The derivatives should be effectively equal.
+///     eq(t, effectively_equal, result_derivative, derivative);
+/// }
+/// ```
+///
+/// Overlapping values cause NaN derivatives:
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// let list = &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)];
+/// let result = derivatives::first_order_time_shifted(list);
+/// assert_eq!(list.len() - 2, result.len());
+///
+/// for (independent, derivative) in result {
+///     assert_eq!(independent, 1.0);
+///     assert!(derivative.is_nan());
+/// }
+/// ```
+#[must_use]
+#[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
+pub fn first_order_time_shifted<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    if list.len() < 3 {
+        return Box::default();
+    }
+
+    let mut derivative = Vec::with_capacity(list.len() - 2);
+
+    // Skips the first and last index.
+    for i in 1..(list.len() - 1) {
+        derivative.push(
+            derivative_time_shifted(i, list).expect("`1 < i < list.len() - 1`, this is safe"),
+        );
+    }
+
+    derivative.into_boxed_slice()
+}
+
+/// Calculates [`first_order_time_shifted`], typing the derivatives with the rate unit `U`.
+///
+/// [`first_order_time_shifted`] returns plain [`f64`] derivatives; this saves callers from
+/// rewrapping every one of them in the appropriate [`Valued`] unit.
+///
+/// # Errors
+///
+/// The same as [`first_order_time_shifted`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{
+/// #     statistics::derivatives,
+/// #     units::{
+/// #         Float, Meters, Seconds,
+/// #         composition::{Power, UnitList, UnitListNull, Valued},
+/// #     },
+/// # };
+/// #
+/// type Velocity = UnitList<Meters, UnitList<Power<Seconds, -1>, UnitListNull>>;
+///
+/// let position = [(0.0, 0.0), (1.0, 2.0), (2.0, 4.0), (3.0, 6.0)];
+/// let velocity = derivatives::first_order_time_shifted_valued::<_, _, Velocity>(&position);
+///
+/// assert_eq!(
+///     velocity,
+///     [(1.0, Valued::new(2.0)), (2.0, Valued::new(2.0))],
+/// );
+/// ```
+#[must_use]
+pub fn first_order_time_shifted_valued<T: Float, F: Float, U: Unit + Default>(
+    list: &[(T, F)],
+) -> Vec<(T, Valued<f64, U>)> {
+    first_order_time_shifted(list)
+        .into_iter()
+        .map(|(t, derivative)| (t, Valued::from_unit_default(derivative)))
+        .collect()
+}
+
+/// Calculates the numerical second derivative of `F` with respect to `T` at `index` using
+/// time-shifted data points.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// This recognizes that traditional "rise over run" derivatives calculate the average derivative,
+/// at the center of a time interval. It is a normal [`central_difference_derivative`] of the
+/// [`first_order_time_shifted`] derivative, but running from the midpoint of `t_1` to `t_2`
+/// and the midpoint of `t_2` to `t_3`, instead of from `t_1` to `t_3`.
+///
+/// Here's the math written out as a Typst expression, calculating acceleration from change in
+/// velocity over time:
+///
+/// ```typst
+/// $$$
+///     a_2 &= 2 * (
+///         v_("avg", 23)
+///         - v_("avg", 12)
+///     ) / (Delta t_13)\
+///
+///     a_2 &= 2 * (
+///         (x_3 - x_2) / (t_3 - t_2)
+///         - (x_2 - x_1) / (t_2 - t_1)
+///     ) / (t_3 - t_1)\
+/// $$$
+/// ```
+///
+/// For details, see the Typst document `/docs/derivatives.typ`. It explains the math further and
+/// derives it. The math is based on William Leonard's article "Dangers of Automated Data
+/// Analysis," pub. _The Physics Teacher,_ vol. 35, April 1996, pp. 220--222.
+///
+/// # Errors
+///
+/// - Returns [`OutOfBoundsIndexError`] if `index - 1` or `index + 1` is out of bounds in `list`.
+/// - Overlapping `T` values will return a [`f64::NAN`] as their second derivative.
+///
+/// # Units
+///
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 2>`][`Per`]. Wrap it with
+/// [`Float::new`] to get one, like [`first_order_per`] does for the first order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives::second_derivative_time_shifted;
+/// #
+/// // Normal behavior:
+/// let list = &[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
+/// let derivative =
+///     2.0 * (
+///         (5.0_f64 - 3.0) / (2.0 - 1.0) // f'_(avg,23)
+///         - (3.0 - 1.0) / (1.0 - 0.0)
+///         // f'_(avg,12)
+///     ) / (2.0 - 0.0); // Delta t_13
+///
+/// assert_eq!(
+///     second_derivative_time_shifted(1, list),
+///     Ok((1.0, derivative))
+/// );
+///
+/// // Overlapping values should cause NaN derivatives:
+/// let (independent, derivative) =
+///     second_derivative_time_shifted(1, &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)]).unwrap();
+/// dbg!(independent, derivative);
+///
+/// assert_eq!(independent, 1.0);
+/// assert!(derivative.is_nan());
+/// ```
+pub fn second_derivative_time_shifted<T: Float, F: Float>(
+    index: usize,
+    list: &[(T, F)],
+) -> Result<(T, f64), OutOfBoundsIndexError> {
+    let get = |index: usize| {
+        let (t, f) = oob!(list.get(index));
+        Ok((t.get(), f.get()))
+    };
+
+    let (independent_1, dependent_1) = get(oob!(index.checked_sub(1)))?;
+    let (independent_2, dependent_2) = get(index)?;
+    let (independent_3, dependent_3) = get(index + 1)?;
+
+    let derivative_avg_12 = (dependent_2 - dependent_1) / (independent_2 - independent_1);
+    let derivative_avg_23 = (dependent_3 - dependent_2) / (independent_3 - independent_2);
+
+    let delta_independent_13 = independent_3 - independent_1;
+
+    Ok((
+        T::new(independent_2.get()),
+        2.0 * (derivative_avg_23 - derivative_avg_12) / delta_independent_13,
+    ))
+}
+
+/// Calculates the numerical second derivative of `F` with respect to `T` using time-shifted data
+/// points.
+///
+/// - Does not include the first or last data points.
+/// - Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// This recognizes that traditional "rise over run" derivatives calculate the average derivative,
+/// at the center of a time interval. This adjusts to calculated derivatives based on midpoints
+/// instead of the start of intervals.
+///
+/// For details, see the Typst document `/docs/derivatives.typ`. It explains the math further and
+/// derives it. The math is based on William Leonard's article "Dangers of Automated Data
+/// Analysis," pub. _The Physics Teacher,_ vol. 35, April 1996, pp. 220--222.
+///
+/// # Errors
+///
+/// - `list.len() < 3` returns an empty list.
+/// - Overlapping `T` values will return a [`f64::NAN`] as their second derivative.
+///
+/// # Units
+///
+/// The output [`f64`] is most correctly typed as [`Per<F, T, 2>`][`Per`]. Wrap it with
+/// [`Float::new`] to get one, like [`first_order_per`] does for the first order.
+///
+/// # Examples
+///
+/// Comparing with the actual second derivative:
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `sin(t)` from `t = 0` to `t = 2`.
+/// let list = (0..=10)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.2;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+///
+/// // `-sin(t)` (because `d^2/dx^2 sin(t) = -sin(t)`) from `t = 0` to `t = 2`.
+/// let actual = list.iter().map(|(t, _)| (*t, -t.sin())).collect::<Box<_>>();
+/// assert_eq!(actual.len(), list.len());
+///
+/// // The time-shifted numerical second derivative.
+/// let result = derivatives::second_order_time_shifted(&list);
+/// assert_eq!(result.len(), actual.len() - 2);
+///
+/// // Your data may have more or less error than this!
+/// let accepted_error = 0.005;
+/// // Assert that the two values are within `accepted_error` of each other.
+/// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
+///
+/// for i in 0..result.len() {
+///     let (t, derivative) = actual[i + 1];
+///     let (result_t, result_derivative) = result[i];
+///
+///     // The independent variables should remain unchanged.
+///     eq(t, 0.000_000_000_1, result_t, t);
+///     // The derivatives should be within `accepted_error`.
+///     eq(t, accepted_error, result_derivative, derivative);
+/// }
+/// ```
+///
+/// Comparing it to the values calculated by Vernier's Logger Pro®, version 3.16.2:
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// const EXPECTED: [(f64, f64); 13] = [
+///     (0.2, -0.198_007_982_037),
+///     (0.4, -0.388_122_010_68),
+///     (0.6, -0.562_762_839_547),
+///     (0.8, -0.714_968_089_903),
+///     (1.0, -0.838_669_818_726),
+///     (1.2, -0.928_936_428_452),
+///     (1.4, -0.982_169_274_205),
+///     (1.6, -0.996_246_130_409),
+///     (1.8, -0.970_605_797_23),
+///     (2.0, -0.906_270_473_839),
+///     (2.2, -0.805_805_006_559),
+///     (2.4, -0.673_214_636_531),
+///     (2.6, -0.513_785_323_397),
+/// ];
+///
+/// // `sin(t)` from `t = 0` to `t = 2.8`.
+/// let list = (0..15)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.2;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+/// assert_eq!(list.len(), EXPECTED.len() + 2);
+///
+/// // The time-shifted numerical second derivative.
+/// let result = derivatives::second_order_time_shifted(&list);
+/// assert_eq!(result.len(), EXPECTED.len());
+///
+/// let effectively_equal = 0.000_000_000_1;
+/// // Assert that the two values are within `error` of each other.
+/// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
+///
+/// for i in 0..result.len() {
+///     let (t, derivative) = EXPECTED[i];
+///     let (result_t, result_derivative) = result[i];
+///
+///     // The independent variables should remain unchanged.
+///     eq(t, effectively_equal, result_t, t);
+///     // The derivatives should be effectively equal.
+///     eq(t, effectively_equal, result_derivative, derivative);
+/// }
+/// ```
+///
+/// Overlapping values cause NaN second derivatives:
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// let list = &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)];
+/// let result = derivatives::second_order_time_shifted(list);
+/// assert_eq!(list.len() - 2, result.len());
+///
+/// for (independent, derivative) in result {
+///     assert_eq!(independent, 1.0);
+///     assert!(derivative.is_nan());
+/// }
+/// ```
+#[must_use]
+#[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
+pub fn second_order_time_shifted<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    if list.len() < 3 {
+        return Box::default();
+    }
+
+    let mut derivative = Vec::with_capacity(list.len() - 2);
+
+    // Skips the first and last index.
+    for i in 1..(list.len() - 1) {
+        derivative.push(
+            second_derivative_time_shifted(i, list)
+                .expect("`1 < i < list.len() - 1`, this is safe"),
+        );
+    }
+
+    derivative.into_boxed_slice()
+}
+
+/// Calculates the curvature of the curve traced by `F` as a function of `T`.
+///
+/// - Does not include the first or last data points.
+/// - Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// The curvature is how sharply the curve bends at each point, the inverse of the radius of the
+/// circle that best fits the curve there:
+///
+/// ```typst
+/// $kappa = abs(f'') / (1 + f'^2)^(3/2)$
+/// ```
+///
+/// The derivatives are calculated using [`first_order_time_shifted`] and
+/// [`second_order_time_shifted`]. A straight line has a curvature of zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // A parabola, `f = t^2`, which has a curvature of `2` at its vertex.
+/// let list = (-10..=10)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.1;
+///         (t, t * t)
+///     })
+///     .collect::<Box<_>>();
+///
+/// let curvature = derivatives::curvature(&list);
+/// assert_eq!(curvature.len(), list.len() - 2);
+///
+/// let (t, at_vertex) = curvature[9];
+/// assert!(t.abs() < 0.000_000_000_1);
+/// assert!((at_vertex - 2.0).abs() < 0.000_000_000_1);
+/// ```
+#[must_use]
+pub fn curvature<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    first_order_time_shifted(list)
+        .iter()
+        .zip(second_order_time_shifted(list))
+        .map(|((t, first), (_, second))| {
+            (
+                T::new(t.get()),
+                second.abs() / first.mul_add(*first, 1.0).powf(1.5),
+            )
+        })
+        .collect()
+}
+
+/// Calculates the radius of curvature of the curve traced by `F` as a function of `T`, the inverse
+/// of its [`curvature`].
+///
+/// - Does not include the first or last data points.
+/// - Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// This is the radius of the circle that best fits the curve at each point, such as the turning
+/// radius along a path. Where the curve is straight, the radius is [`f64::INFINITY`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // A straight line never turns.
+/// let list = &[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
+/// assert_eq!(&*derivatives::radius_of_curvature(list), &[(1.0, f64::INFINITY)]);
+/// ```
+#[must_use]
+pub fn radius_of_curvature<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    curvature(list)
+        .iter()
+        .map(|(t, curvature)| (T::new(t.get()), curvature.recip()))
+        .collect()
+}
+
+/// Calculates the five-point stencil derivative. Returns `T` at `index` and the derivative of `F`
+/// over `T` at `index`, using the points from `index - 2` to `index + 2`.
+///
+/// This is fourth-order accurate, compared to the second-order accurate
+/// [`central_difference_derivative`], but it assumes that `T` values are uniformly spaced. The
+/// spacing is taken to be the average spacing between `index - 2` and `index + 2`.
+///
+/// Here's the math written out as a Typst expression, where `h` is the spacing of `T` values:
+///
+/// ```typst
+/// $f'_i = (f_(i - 2) - 8 f_(i - 1) + 8 f_(i + 1) - f_(i + 2)) / (12 h)$
+/// ```
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - Returns [`OutOfBoundsIndexError`] if `index - 2` or `index + 2` is out of bounds in `list`.
+/// - Overlapping `T` values at `index - 2` and `index + 2` will return a non-finite derivative.
+///
+/// # Units
+///
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives::five_point_derivative;
+/// #
+/// // `t^3`, which has a derivative of `3t^2`.
+/// let list = [0.0, 1.0, 2.0, 3.0, 4.0].map(|t: f64| (t, t.powi(3)));
+///
+/// // Exact for polynomials up to the fourth degree.
+/// assert_eq!(five_point_derivative(2, &list), Ok((2.0, 12.0)));
+///
+/// assert!(five_point_derivative(1, &list).is_err());
+/// ```
+pub fn five_point_derivative<T: Float, F: Float>(
+    index: usize,
+    list: &[(T, F)],
+) -> Result<(T, f64), OutOfBoundsIndexError> {
+    let get = |index: usize| {
+        let (t, f) = oob!(list.get(index));
+        Ok((t.get(), f.get()))
+    };
+
+    let (independent_1, dependent_1) = get(oob!(index.checked_sub(2)))?;
+    let (_, dependent_2) = get(index - 1)?;
+    let (independent_3, _) = get(index)?;
+    let (_, dependent_4) = get(index + 1)?;
+    let (independent_5, dependent_5) = get(index + 2)?;
+
+    let spacing = (independent_5 - independent_1) / 4.0;
+
+    Ok((
+        T::new(independent_3),
+        8.0f64.mul_add(dependent_4 - dependent_2, dependent_1 - dependent_5) / (12.0 * spacing),
+    ))
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` using [`five_point_derivative`]
+/// wherever possible.
+///
+/// The second and second-to-last items use [`central_difference_derivative`], and the first and
+/// last items use [`forward_difference_derivative`] and [`backward_difference_derivative`], just
+/// like [`first_order`].
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last) and that
+/// the `T` values are uniformly spaced.
+///
+/// # Errors
+///
+/// - `list.len() < 2` returns an empty list.
+/// - Overlapping `T` values will return non-finite values as their derivative.
+///
+/// # Units
+///
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `sin(t)` from `t = 0` to `t = 2`.
+/// let list = (0..=10)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.2;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+///
+/// let result = derivatives::first_order_five_point(&list);
+/// assert_eq!(result.len(), list.len());
+///
+/// // The interior points are far more accurate than `first_order`.
+/// for &(t, derivative) in &result[2..result.len() - 2] {
+///     assert!((derivative - t.cos()).abs() < 0.000_1, "{derivative} != {} @ {t}", t.cos());
+/// }
+/// ```
+#[must_use]
+pub fn first_order_five_point<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    first_order_with_stencil(list, 2, five_point_derivative)
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` for irregularly spaced data.
+///
+/// This resamples the list to `count` uniformly spaced points with [`resample_uniform`], then
+/// applies [`first_order_five_point`].
+///
+/// The output is at the resampled `T` values, not the original ones. The linear interpolation
+/// smooths over curvature between the original points, so choose `count` near `list.len()` unless
+/// the data is dense enough to make that negligible.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - Returns an empty list if `list.len() < 2` or `count < 2`.
+/// - Overlapping `T` values will return non-finite values as their derivative.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `f = 3t + 1` at irregular intervals.
+/// let list = [0.0, 0.3, 1.1, 1.5, 2.8, 4.0].map(|t: f64| (t, 3.0f64.mul_add(t, 1.0)));
+///
+/// let result = derivatives::resample_and_first_order(&list, 9);
+/// assert_eq!(result.len(), 9);
+/// assert_eq!(result[4].0, 2.0);
+/// for &(_, derivative) in &result {
+///     assert!((derivative - 3.0).abs() < 0.000_000_1);
+/// }
+/// ```
+#[must_use]
+pub fn resample_and_first_order<T: Float, F: Float>(
+    list: &[(T, F)],
+    count: usize,
+) -> Box<[(T, f64)]> {
+    first_order_five_point(&resample_uniform(list, count))
+}
+
+/// Calculates the Richardson extrapolated derivative. Returns `T` at `index` and the derivative of
+/// `F` over `T` at `index`, using the points from `index - 2` to `index + 2`.
+///
+/// This calculates the [`central_difference_derivative`] twice, once using the neighboring points
+/// (`D_1`) and once using the points two indices away (`D_2`), then extrapolates away the leading
+/// error term:
+///
+/// ```typst
+/// $f'_i = (4 D_1 - D_2) / 3$
+/// ```
+///
+/// This is fourth-order accurate for uniformly spaced `T` values, in which case it is equivalent
+/// to [`five_point_derivative`]. For non-uniformly spaced data, the extrapolation is only
+/// approximate.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - Returns [`OutOfBoundsIndexError`] if `index - 2` or `index + 2` is out of bounds in `list`.
+/// - Overlapping `T` values will return a non-finite derivative.
+///
+/// # Units
+///
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives::richardson_derivative;
+/// #
+/// // `t^3`, which has a derivative of `3t^2`.
+/// let list = [0.0, 1.0, 2.0, 3.0, 4.0].map(|t: f64| (t, t.powi(3)));
+///
+/// assert_eq!(richardson_derivative(2, &list), Ok((2.0, 12.0)));
+///
+/// assert!(richardson_derivative(3, &list).is_err());
+/// ```
+pub fn richardson_derivative<T: Float, F: Float>(
+    index: usize,
+    list: &[(T, F)],
+) -> Result<(T, f64), OutOfBoundsIndexError> {
+    let get = |index: usize| {
+        let (t, f) = oob!(list.get(index));
+        Ok((t.get(), f.get()))
+    };
+
+    let (independent_1, dependent_1) = get(oob!(index.checked_sub(2)))?;
+    let (independent_2, dependent_2) = get(index - 1)?;
+    let (independent_3, _) = get(index)?;
+    let (independent_4, dependent_4) = get(index + 1)?;
+    let (independent_5, dependent_5) = get(index + 2)?;
+
+    let derivative_near = (dependent_4 - dependent_2) / (independent_4 - independent_2);
+    let derivative_far = (dependent_5 - dependent_1) / (independent_5 - independent_1);
+
+    Ok((
+        T::new(independent_3),
+        4.0f64.mul_add(derivative_near, -derivative_far) / 3.0,
+    ))
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` using [`richardson_derivative`]
+/// wherever possible.
+///
+/// The second and second-to-last items use [`central_difference_derivative`], and the first and
+/// last items use [`forward_difference_derivative`] and [`backward_difference_derivative`], just
+/// like [`first_order`].
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - `list.len() < 2` returns an empty list.
+/// - Overlapping `T` values will return non-finite values as their derivative.
+///
+/// # Units
+///
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `sin(t)` from `t = 0` to `t = 2`.
+/// let list = (0..=10)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.2;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+///
+/// let result = derivatives::first_order_richardson(&list);
+/// assert_eq!(result.len(), list.len());
+///
+/// // The interior points are far more accurate than `first_order`.
+/// for &(t, derivative) in &result[2..result.len() - 2] {
+///     assert!((derivative - t.cos()).abs() < 0.000_1, "{derivative} != {} @ {t}", t.cos());
+/// }
+/// ```
+#[must_use]
+pub fn first_order_richardson<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    first_order_with_stencil(list, 2, richardson_derivative)
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` using `stencil` for every index
+/// at least `reach` indices away from the ends of `list`.
+///
+/// Falls back on the same algorithms as [`first_order`] for the indices closer to the ends.
+fn first_order_with_stencil<T: Float, F: Float>(
+    list: &[(T, F)],
+    reach: usize,
+    stencil: impl Fn(usize, &[(T, F)]) -> Result<(T, f64), OutOfBoundsIndexError>,
+) -> Box<[(T, f64)]> {
+    if list.len() < 2 {
+        return Box::default();
+    }
+
+    let last_index = list.len() - 1;
+
+    (0..list.len())
+        .map(|index| {
+            if index == 0 {
+                forward_difference_derivative(index, list)
+            } else if index == last_index {
+                backward_difference_derivative(index, list)
+            } else if index < reach || last_index - index < reach {
+                central_difference_derivative(index, list)
+            } else {
+                stencil(index, list)
+            }
+            .expect("`index` is at least `reach` from the ends of `list`, this is safe")
+        })
+        .collect()
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T`, estimating the error of each
+/// derivative by comparing estimates of different orders of accuracy.
+///
+/// For the middle items, this reports the [`richardson_derivative`], with the difference between it
+/// and the [`central_difference_derivative`] (at the native spacing) as its uncertainty. Richardson
+/// extrapolation cancels the leading error term of the central difference, so that difference
+/// estimates the error of the central difference, and is a conservative error bar for the
+/// extrapolated value.
+///
+/// Where there are not enough points for Richardson extrapolation, the same idea applies one order
+/// lower:
+///
+/// - The second and second-to-last items report the [`central_difference_derivative`], with its
+///   difference from the one-sided first-order difference as the uncertainty.
+/// - The first and last items report the [`forward_difference_second_order`] and
+///   [`backward_difference_second_order`], with their difference from the first-order
+///   [`forward_difference_derivative`] and [`backward_difference_derivative`] as the uncertainty.
+///
+/// This is only a meaningful estimate for smooth data; noise will dominate the reported error.
+/// Like [`first_order_richardson`], the middle items assume uniformly spaced data.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - `list.len() < 3` returns an empty list.
+/// - Overlapping `T` values will return non-finite values as their derivative and uncertainty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `sin(t)` from `t = 0` to `t = 2`.
+/// let list = (0..=20)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.1;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+///
+/// let result = derivatives::first_order_with_error(&list);
+/// assert_eq!(result.len(), list.len());
+///
+/// for (t, derivative) in result {
+///     let error = (derivative.value() - t.cos()).abs();
+///
+///     // The reported uncertainty covers the actual error.
+///     assert!(error <= *derivative.uncertainty(), "{error} > {derivative} @ {t}");
+/// }
+/// ```
+#[must_use]
+#[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
+pub fn first_order_with_error<T: Float, F: Float>(
+    list: &[(T, F)],
+) -> Box<[(T, UncertainFloat<f64>)]> {
+    if list.len() < 3 {
+        return Box::default();
+    }
+
+    let last_index = list.len() - 1;
+
+    (0..list.len())
+        .map(|index| {
+            let (estimate, reference) = if index == 0 {
+                (
+                    forward_difference_second_order(index, list),
+                    forward_difference_derivative(index, list),
+                )
+            } else if index == last_index {
+                (
+                    backward_difference_second_order(index, list),
+                    backward_difference_derivative(index, list),
+                )
+            } else if index == 1 {
+                (
+                    central_difference_derivative(index, list),
+                    forward_difference_derivative(index, list),
+                )
+            } else if index == last_index - 1 {
+                (
+                    central_difference_derivative(index, list),
+                    backward_difference_derivative(index, list),
+                )
+            } else {
+                (
+                    richardson_derivative(index, list),
+                    central_difference_derivative(index, list),
+                )
+            };
+
+            let (t, estimate) =
+                estimate.expect("`index` is far enough from the ends of `list`, this is safe");
+            let (_, reference) =
+                reference.expect("`index` is far enough from the ends of `list`, this is safe");
+
+            (
+                t,
+                UncertainFloat::new(estimate, (estimate - reference).abs()),
+            )
+        })
+        .collect()
+}
+
+/// The algorithms that [`differentiate`] can use to calculate a first-order numerical derivative.
+#[cfg_attr(any(feature = "serde", test), derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum DerivativeMethod {
+    /// Traditional finite differences, see [`first_order`].
+    #[default]
+    FiniteDifference,
+    /// Time-shifted derivatives, see [`first_order_time_shifted`].
+    TimeShifted,
+    /// Five-point stencils, see [`first_order_five_point`].
+    FivePoint,
+    /// Richardson extrapolation, see [`first_order_richardson`].
+    ///
+    /// On uniformly spaced data, this is identical to [`Self::FivePoint`] (up to floating-point
+    /// rounding). It only gives different results when the spacing is non-uniform.
+    Richardson,
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` using the given
+/// [`DerivativeMethod`].
+///
+/// This is a single entry point that dispatches to the individual functions in this module, see
+/// the documentation of each [`DerivativeMethod`] for details on their behavior.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// See the documentation for the function each [`DerivativeMethod`] dispatches to.
+///
+/// # Units
+///
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives::{self, DerivativeMethod};
+/// #
+/// let list = &[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
+///
+/// assert_eq!(
+///     derivatives::differentiate(list, DerivativeMethod::FiniteDifference),
+///     derivatives::first_order(list),
+/// );
+/// assert_eq!(
+///     derivatives::differentiate(list, DerivativeMethod::TimeShifted),
+///     derivatives::first_order_time_shifted(list),
+/// );
+/// ```
+#[must_use]
+pub fn differentiate<T: Float, F: Float>(
+    list: &[(T, F)],
+    method: DerivativeMethod,
+) -> Box<[(T, f64)]> {
+    match method {
+        DerivativeMethod::FiniteDifference => first_order(list),
+        DerivativeMethod::TimeShifted => first_order_time_shifted(list),
+        DerivativeMethod::FivePoint => first_order_five_point(list),
+        DerivativeMethod::Richardson => first_order_richardson(list),
+    }
+}
+
+/// Calculates the numerical derivative of `F` with respect to `T` by fitting a polynomial to a
+/// sliding window of points.
+///
+/// For each point, this fits a polynomial of degree `degree` to the `window` points surrounding it
+/// using least squares, then differentiates the polynomial analytically at that point. This is a
+/// generalization of the Savitzky--Golay filter to non-uniformly spaced data, and is a good choice
+/// for noisy data: larger windows smooth out more noise, and higher degrees follow sharper
+/// features.
+///
+/// The window is centered on each point where possible. Near the ends of the list, the window is
+/// shifted inwards so that it remains `window` points wide, so the first and last few points are
+/// differentiated at the edge of their fit and will be less accurate.
+///
+/// If the underlying function is a polynomial of degree `degree` or less, this recovers its
+/// derivative exactly (up to floating-point error).
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - `degree == 0`, `window <= degree`, or `list.len() < window` returns an empty list.
+/// - Windows with fewer than `degree + 1` distinct `T` values will return [`f64::NAN`] as their
+///   derivative.
+///
+/// # Units
+///
+/// See [`first_order_per`] for typing the result as [`Per<F, T, 1>`][`Per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `sin(t)` from `t = 0` to `t = 2`.
+/// let list = (0..=20)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.1;
+///         (t, t.sin())
+///     })
+///     .collect::<Box<_>>();
+///
+/// let result = derivatives::local_polynomial_derivative(&list, 7, 3);
+/// assert_eq!(result.len(), list.len());
+///
+/// // The ends are fit off-center, so they are less accurate than the interior.
+/// for &(t, derivative) in &result[3..result.len() - 3] {
+///     assert!((derivative - t.cos()).abs() < 0.001, "{derivative} != {} @ {t}", t.cos());
+/// }
+/// for &(t, derivative) in result.iter() {
+///     assert!((derivative - t.cos()).abs() < 0.01, "{derivative} != {} @ {t}", t.cos());
+/// }
+///
+/// // The window must be larger than the degree.
+/// assert!(derivatives::local_polynomial_derivative(&list, 3, 3).is_empty());
+/// ```
+#[must_use]
+pub fn local_polynomial_derivative<T: Float, F: Float>(
+    list: &[(T, F)],
+    window: usize,
+    degree: usize,
+) -> Box<[(T, f64)]> {
+    if degree == 0 || window <= degree || list.len() < window {
+        return Box::default();
+    }
+
+    (0..list.len())
+        .map(|index| {
+            // Center the window on `index`, shifting it inwards near the ends of the list.
+            let start = index.saturating_sub(window / 2).min(list.len() - window);
+            let center = list[index].0.get();
+            let points = &list[start..start + window];
+
+            // Fit around the center point, scaled to `[-1, 1]` to keep the fit well-conditioned.
+            let scale = points
+                .iter()
+                .map(|(t, _)| (t.get() - center).abs())
+                .fold(0.0, f64::max);
+            let coefficients = polynomial_fit(
+                points
+                    .iter()
+                    .map(|(t, f)| ((t.get() - center) / scale, f.get())),
+                degree,
+            );
+
+            // The derivative of the polynomial at `x = 0` is the linear coefficient, which has to
+            // be unscaled.
+            let derivative = coefficients.map_or(f64::NAN, |coefficients| coefficients[1] / scale);
+
+            (T::new(center), derivative)
+        })
+        .collect()
+}
+
+/// Smooths `F` with a Savitzky--Golay filter, fitting a polynomial of degree `poly_order` to a
+/// sliding window of `window` points and replacing each value with the fit's value at that point.
+///
+/// Unlike [`moving_average_pairs`], this preserves the shape of peaks and other features that a
+/// polynomial of degree `poly_order` can follow, which makes it a good step before differentiating
+/// noisy data (e.g., taking a second derivative). Near the ends of the list, the window is shifted
+/// inwards instead of shrinking, so every point is smoothed with `window` points.
+///
+/// This assumes that `T` values are uniformly spaced, fitting against the indices of the points
+/// rather than their `T` values. For non-uniformly spaced data, see
+/// [`local_polynomial_derivative`] or [`resample_uniform`].
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// - An even `window` is widened by one to make it odd, and `poly_order` is clamped to at most
+///   `window - 1`, so that the fit is never underdetermined.
+/// - `list.len() < window` returns the list unchanged (converted to [`f64`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // A parabola with alternating noise.
+/// let list = (0..=20)
+///     .map(|i| {
+///         let t = f64::from(i) * 0.1;
+///         let noise = if i % 2 == 0 { 0.05 } else { -0.05 };
+///         (t, t.powi(2) + noise)
+///     })
+///     .collect::<Box<_>>();
+///
+/// let smoothed = derivatives::savitzky_golay(&list, 7, 2);
+/// assert_eq!(smoothed.len(), list.len());
+///
+/// for (&(t, smooth), &(_, noisy)) in smoothed.iter().zip(&list) {
+///     let exact = t.powi(2);
+///     assert!((smooth - exact).abs() < (noisy - exact).abs(), "{smooth} @ {t}");
+/// }
+///
+/// // Too short to smooth.
+/// assert_eq!(*derivatives::savitzky_golay(&list[..3], 7, 2), list[..3]);
+/// ```
+#[must_use]
+pub fn savitzky_golay<T: Float, F: Float>(
+    list: &[(T, F)],
+    window: usize,
+    poly_order: usize,
+) -> Box<[(T, f64)]> {
+    let window = window | 1;
+    let poly_order = poly_order.min(window - 1);
+
+    if list.len() < window {
+        return list
+            .iter()
+            .map(|(t, f)| (T::new(t.get()), f.get()))
+            .collect();
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "windows are nowhere near large enough to lose precision"
+    )]
+    (0..list.len())
+        .map(|index| {
+            // Center the window on `index`, shifting it inwards near the ends of the list.
+            let start = index.saturating_sub(window / 2).min(list.len() - window);
+            let points = &list[start..start + window];
+
+            // Fit against offsets from `index`, scaled to `[-1, 1]` to keep the fit
+            // well-conditioned, so that the value at `index` is the constant term.
+            let scale = (window - 1) as f64;
+            let coefficients = polynomial_fit(
+                points.iter().enumerate().map(|(offset, (_, f))| {
+                    (((start + offset) as f64 - index as f64) / scale, f.get())
+                }),
+                poly_order,
+            );
+
+            (
+                T::new(list[index].0.get()),
+                coefficients.map_or(f64::NAN, |coefficients| coefficients[0]),
+            )
+        })
+        .collect()
+}
+
+/// Fits a polynomial of degree `degree` to `points` using least squares, returning its
+/// coefficients from the constant term upwards.
+///
+/// Returns [`None`] if the fit is underdetermined (e.g., if there are fewer than `degree + 1`
+/// distinct `x` values).
+fn polynomial_fit(points: impl Iterator<Item = (f64, f64)>, degree: usize) -> Option<Box<[f64]>> {
+    let size = degree + 1;
+
+    // The augmented matrix of the normal equations, `[A^T A | A^T y]`, where `A` is the
+    // Vandermonde matrix of the `x` values.
+    let mut matrix = vec![vec![0.0; size + 1]; size];
+    for (x, y) in points {
+        let mut powers = vec![1.0; 2 * size - 1];
+        for power in 1..powers.len() {
+            powers[power] = powers[power - 1] * x;
+        }
+
+        for (row_index, row) in matrix.iter_mut().enumerate() {
+            for (column, value) in row.iter_mut().take(size).enumerate() {
+                *value += powers[row_index + column];
+            }
+            row[size] = powers[row_index].mul_add(y, row[size]);
+        }
+    }
+
+    // Gaussian elimination with partial pivoting.
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))?;
+        if matrix[pivot][column].abs() < f64::EPSILON {
+            return None;
+        }
+        matrix.swap(column, pivot);
+
+        for row in (column + 1)..size {
+            let (pivot_rows, rows) = matrix.split_at_mut(row);
+            let pivot_row = &pivot_rows[column];
+            let factor = rows[0][column] / pivot_row[column];
+            for (value, pivot_value) in rows[0].iter_mut().zip(pivot_row).skip(column) {
+                *value = factor.mul_add(-pivot_value, *value);
+            }
+        }
+    }
+
+    // Back substitution.
+    let mut coefficients = vec![0.0; size];
+    for row in (0..size).rev() {
+        let sum = ((row + 1)..size).fold(matrix[row][size], |sum, column| {
+            matrix[row][column].mul_add(-coefficients[column], sum)
+        });
+        coefficients[row] = sum / matrix[row][row];
+    }
+
+    Some(coefficients.into_boxed_slice())
+}
+
+/// Calculates the total variation of `F` over a list of points, the sum of the absolute changes
+/// between consecutive points: `sum_i |f[i + 1] - f[i]|`.
+///
+/// This is a standard measure of how rough or noisy a signal is, and can help decide how much
+/// smoothing to apply (e.g., how wide a window to use for [`local_polynomial_derivative`]). For a
+/// monotonic series, it is just the total rise (or fall).
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// Returns `0.0` if `list.len() < 2`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // Monotonic, so this is the total rise.
+/// let rising = [(0.0, 1.0), (1.0, 2.5), (2.0, 2.5), (3.0, 7.0)];
+/// assert_eq!(derivatives::total_variation(&rising), 6.0);
+///
+/// // Oscillating with the same endpoints, so this is much larger than the total rise.
+/// let oscillating = [(0.0, 1.0), (1.0, 5.0), (2.0, -1.0), (3.0, 7.0)];
+/// assert_eq!(derivatives::total_variation(&oscillating), 4.0 + 6.0 + 8.0);
+///
+/// assert_eq!(derivatives::total_variation(&[(0.0, 1.0)]), 0.0);
+/// ```
+#[must_use]
+pub fn total_variation<T: Float, F: Float>(list: &[(T, F)]) -> f64 {
+    list.windows(2)
+        .map(|window| (window[1].1.get() - window[0].1.get()).abs())
+        .sum()
+}
+
+/// Suggests a window size for smoothing a noisy list of points before (or while) differentiating
+/// it, such as for [`local_polynomial_derivative`].
+///
+/// This is a heuristic, not an optimal choice, and should be treated as a starting point. It
+/// measures the roughness of the data as the ratio of its [`total_variation`] to its range
+/// (`max(f) - min(f)`), which is `1` for monotonic data and grows as noise adds wiggles. Averaging
+/// `n` points reduces uncorrelated noise by a factor of about `sqrt(n)`, so it suggests a window of
+/// about `roughness^2` points, targeting a roughness of about `1`.
+///
+/// For lists of at least three points, the result is always odd (so that windows can be centered
+/// on a point), at least `3`, and no larger than the list.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// If `list.len() < 3`, this returns `list.len()`, the largest window possible.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // A smooth line needs no more than the minimum window.
+/// let smooth = (0..50).map(|i| (f64::from(i), f64::from(i) * 0.5)).collect::<Box<_>>();
+/// assert_eq!(derivatives::suggest_smoothing_window(&smooth), 3);
+///
+/// // The same line with alternating noise needs more.
+/// let noisy = smooth
+///     .iter()
+///     .enumerate()
+///     .map(|(i, &(t, f))| (t, if i % 2 == 0 { f + 1.0 } else { f - 1.0 }))
+///     .collect::<Box<_>>();
+/// let window = derivatives::suggest_smoothing_window(&noisy);
+/// assert!(window > 3);
+/// assert_eq!(window % 2, 1);
+/// ```
+#[must_use]
+pub fn suggest_smoothing_window<T: Float, F: Float>(list: &[(T, F)]) -> usize {
+    const MIN_WINDOW: usize = 3;
+
+    if list.len() < MIN_WINDOW {
+        return list.len();
+    }
+
+    let (min, max) = list
+        .iter()
+        .map(|(_, f)| f.get())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), f| {
+            (min.min(f), max.max(f))
+        });
+    let range = max - min;
+
+    // Constant data has no noise to smooth.
+    if range <= 0.0 {
+        return MIN_WINDOW;
+    }
+
+    let roughness = total_variation(list) / range;
+
+    // The largest odd window that fits in the list.
+    let max_window = if list.len().is_multiple_of(2) {
+        list.len() - 1
+    } else {
+        list.len()
+    };
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "`roughness >= 1`, and the result is clamped to the length of the list"
+    )]
+    let window = (roughness.powi(2).round() as usize).clamp(MIN_WINDOW, max_window);
+
+    // Round up to the nearest odd window, which will still fit because `max_window` is odd.
+    window | 1
+}
+
+/// Measures how uniformly spaced the `T` values of a list of points are, as the coefficient of
+/// variation of the intervals between consecutive points: `stddev(Δt) / |mean(Δt)|`.
+///
+/// `0` means perfectly uniform spacing, and the result grows as the spacing gets more irregular.
+/// Several algorithms (e.g., [`first_order_five_point`] and [`first_order_richardson`]) assume
+/// uniform spacing, so this can help decide whether they are appropriate for a data set or whether
+/// to fall back on algorithms that handle any spacing, like [`first_order`].
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// Returns `0.0` if `list.len() < 3`, because zero or one intervals are trivially uniform.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// let uniform = [(0.0, 1.0), (0.5, 4.0), (1.0, 9.0), (1.5, 16.0)];
+/// assert_eq!(derivatives::spacing_uniformity(&uniform), 0.0);
+///
+/// // Intervals of `1`, `1`, and `4`.
+/// let irregular = [(0.0, 1.0), (1.0, 4.0), (2.0, 9.0), (6.0, 16.0)];
+/// assert!(derivatives::spacing_uniformity(&irregular) > 0.8);
+///
+/// assert_eq!(derivatives::spacing_uniformity(&[(0.0, 1.0), (1.0, 2.0)]), 0.0);
+/// ```
+#[must_use]
+pub fn spacing_uniformity<T: Float, F: Float>(list: &[(T, F)]) -> f64 {
+    if list.len() < 3 {
+        return 0.0;
+    }
+
+    let intervals: Box<[f64]> = list
+        .windows(2)
+        .map(|window| window[1].0.get() - window[0].0.get())
+        .collect();
+
+    crate::statistics::stddev(&intervals) / crate::statistics::mean(&intervals).abs()
+}
+
+/// Splits a list of points into segments over which `F` is monotonically increasing or
+/// decreasing, splitting at local extrema.
+///
+/// Each segment is an inclusive range of indices `(start, end)`, and consecutive segments share
+/// the index of the extremum between them. The segments cover the whole list.
+///
+/// Flat regions (consecutive equal `F` values) never start a new segment: they belong to whichever
+/// segment they appear in, so segments are only non-strictly monotonic. A flat region at an
+/// extremum stays with the segment before it, and a list that is entirely flat is one segment.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// Returns an empty list if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// let list = [0.0, 1.0, 3.0, 3.0, 2.0, 2.0, 0.0, 4.0]
+///     .iter()
+///     .enumerate()
+///     .map(|(i, &f)| (i as f64, f))
+///     .collect::<Box<_>>();
+///
+/// // Rising to `3`, falling (including the flat `2, 2`) to `0`, then rising again.
+/// assert_eq!(*derivatives::monotonic_segments(&list), [(0, 3), (3, 6), (6, 7)]);
+/// assert_eq!(*derivatives::monotonic_segments(&[(0.0, 1.0)]), [(0, 0)]);
+/// ```
+#[must_use]
+pub fn monotonic_segments<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(usize, usize)]> {
+    if list.is_empty() {
+        return Box::new([]);
+    }
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    // Whether the current segment is increasing, or `None` if it has been flat so far.
+    let mut increasing = None;
+
+    for (index, window) in list.windows(2).enumerate() {
+        let (previous, current) = (window[0].1.get(), window[1].1.get());
+        let rising = match current.partial_cmp(&previous) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Less) => false,
+            // Flat (or `NaN`).
+            _ => continue,
+        };
+
+        match increasing {
+            Some(increasing) if increasing != rising => {
+                segments.push((start, index));
+                start = index;
+            }
+            _ => (),
+        }
+        increasing = Some(rising);
+    }
+    segments.push((start, list.len() - 1));
+
+    segments.into_boxed_slice()
+}
+
+/// Resamples a list of points to `count` uniformly spaced `T` values, from the first `T` value to
+/// the last, linearly interpolating `F` between the original points.
+///
+/// This allows using algorithms that assume uniform spacing (like [`first_order_five_point`]) on
+/// irregularly spaced data, at the cost of smoothing over any curvature between the original
+/// points. See [`spacing_uniformity`] to check whether this is necessary.
+///
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+///
+/// # Errors
+///
+/// Returns an empty list if `list.len() < 2` or `count < 2`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// let irregular = [(0.0, 0.0), (1.0, 2.0), (4.0, 8.0)];
+///
+/// assert_eq!(
+///     *derivatives::resample_uniform(&irregular, 5),
+///     [(0.0, 0.0), (1.0, 2.0), (2.0, 4.0), (3.0, 6.0), (4.0, 8.0)],
+/// );
+/// assert!(derivatives::resample_uniform(&irregular, 1).is_empty());
+/// ```
+#[must_use]
+pub fn resample_uniform<T: Float, F: Float>(list: &[(T, F)], count: usize) -> Box<[(T, f64)]> {
+    if list.len() < 2 || count < 2 {
+        return Box::new([]);
+    }
+
+    let start = list[0].0.get();
+    let end = list[list.len() - 1].0.get();
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    let spacing = (end - start) / (count - 1) as f64;
+
+    // The index of the original point at the start of the interval being interpolated over.
+    let mut index = 0;
+
+    (0..count)
+        .map(|i| {
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "I can't think of another way to do this"
+            )]
+            // Pin the last point to `end` to avoid floating-point drift.
+            let t = if i == count - 1 {
+                end
+            } else {
+                (i as f64).mul_add(spacing, start)
+            };
+
+            while index < list.len() - 2 && list[index + 1].0.get() < t {
+                index += 1;
+            }
+
+            let (t_0, f_0) = (list[index].0.get(), list[index].1.get());
+            let (t_1, f_1) = (list[index + 1].0.get(), list[index + 1].1.get());
+            let f = (f_1 - f_0).mul_add((t - t_0) / (t_1 - t_0), f_0);
+
+            (T::new(t), f)
+        })
+        .collect()
+}
+
+/// Smooths the dependent (`F`) values of a list of points with a centered moving average, leaving
+/// the independent (`T`) values unchanged.
 ///
-/// for i in 0..result.len() {
-///     let (t, derivative) = EXPECTED[i];
-///     let (result_t, result_derivative) = result[i];
+/// Each smoothed value is the mean of the `window / 2` points on either side of it and the point
+/// itself, so even windows behave like the next odd window. Near the ends of the list, where there
+/// aren't enough points on one side, the window shrinks symmetrically so that it stays centered;
+/// the first and last values are left unsmoothed. A `window` of `0` or `1` returns the values
+/// unchanged.
 ///
-///     // The independent variables should remain unchanged.
-///     eq(t, effectively_equal, result_t, t);
-///     // The derivatives should be effectively equal.
-///     eq(t, effectively_equal, result_derivative, derivative);
-/// }
-/// ```
+/// This is useful on its own, or before differentiating noisy data (see
+/// [`suggest_smoothing_window`] for picking a window). Note that a moving average assumes the
+/// points are roughly evenly spaced in `T`.
 ///
-/// Overlapping values cause NaN derivatives:
+/// # Examples
 ///
 /// ```rust
 /// # use sciutil::statistics::derivatives;
 /// #
-/// let list = &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)];
-/// let result = derivatives::first_order_time_shifted(list);
-/// assert_eq!(list.len() - 2, result.len());
+/// let list = &[(0.0, 1.0), (1.0, 3.0), (2.0, 2.0), (3.0, 4.0), (4.0, 3.0)];
 ///
-/// for (independent, derivative) in result {
-///     assert_eq!(independent, 1.0);
-///     assert!(derivative.is_nan());
-/// }
+/// assert_eq!(
+///     &*derivatives::moving_average_pairs(list, 3),
+///     &[(0.0, 1.0), (1.0, 2.0), (2.0, 3.0), (3.0, 3.0), (4.0, 3.0)],
+/// );
 /// ```
 #[must_use]
-#[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
-pub fn first_order_time_shifted<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
-    if list.len() < 3 {
-        return Box::default();
-    }
+pub fn moving_average_pairs<T: Float, F: Float>(list: &[(T, F)], window: usize) -> Box<[(T, f64)]> {
+    let half_window = window / 2;
 
-    let mut derivative = Vec::with_capacity(list.len() - 2);
+    (0..list.len())
+        .map(|index| {
+            let half_window = half_window.min(index).min(list.len() - 1 - index);
+            let neighbors = &list[index - half_window..=index + half_window];
 
-    // Skips the first and last index.
-    for i in 1..(list.len() - 1) {
-        derivative.push(
-            derivative_time_shifted(i, list).expect("`1 < i < list.len() - 1`, this is safe"),
-        );
-    }
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "I can't think of another way to do this"
+            )]
+            let mean = neighbors.iter().map(|(_, f)| f.get()).sum::<f64>() / neighbors.len() as f64;
 
-    derivative.into_boxed_slice()
+            (T::new(list[index].0.get()), mean)
+        })
+        .collect()
 }
 
-/// Calculates the numerical second derivative of `F` with respect to `T` at `index` using
-/// time-shifted data points.
+/// Calculates the integral of `F` with respect to `T` over the whole list using the trapezoidal
+/// rule.
+///
+/// Each interval contributes `(f[i] + f[i + 1]) / 2 * (t[i + 1] - t[i])`. The trapezoidal rule is
+/// exact for linear data.
 ///
 /// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
-/// This recognizes that traditional "rise over run" derivatives calculate the average derivative,
-/// at the center of a time interval. It is a normal [`central_difference_derivative`] of the
-/// [`first_order_time_shifted`] derivative, but running from the midpoint of `t_1` to `t_2`
-/// and the midpoint of `t_2` to `t_3`, instead of from `t_1` to `t_3`.
+/// # Errors
 ///
-/// Here's the math written out as a Typst expression, calculating acceleration from change in
-/// velocity over time:
+/// Returns `0.0` if `list.len() < 2`.
 ///
-/// ```typst
-/// $$$
-///     a_2 &= 2 * (
-///         v_("avg", 23)
-///         - v_("avg", 12)
-///     ) / (Delta t_13)\
+/// # Examples
 ///
-///     a_2 &= 2 * (
-///         (x_3 - x_2) / (t_3 - t_2)
-///         - (x_2 - x_1) / (t_2 - t_1)
-///     ) / (t_3 - t_1)\
-/// $$$
-/// ```
+/// ```rust
+/// # use sciutil::statistics::derivatives;
+/// #
+/// // `v(t) = 2t`, so the integral from 0 to 4 is `4^2 = 16`.
+/// let list = [0.0, 0.5, 1.0, 2.0, 4.0].map(|t| (t, 2.0 * t));
+/// assert_eq!(derivatives::trapezoidal(&list), 16.0);
 ///
-/// For details, see the Typst document `/docs/derivatives.typ`. It explains the math further and
-/// derives it. The math is based on William Leonard's article "Dangers of Automated Data
-/// Analysis," pub. _The Physics Teacher,_ vol. 35, April 1996, pp. 220--222.
+/// assert_eq!(derivatives::trapezoidal(&[(0.0, 1.0)]), 0.0);
+/// ```
+#[must_use]
+pub fn trapezoidal<T: Float, F: Float>(list: &[(T, F)]) -> f64 {
+    list.windows(2)
+        .map(|window| trapezoid(&window[0], &window[1]))
+        .sum()
+}
+
+/// Calculates the running integral of `F` with respect to `T` using the trapezoidal rule.
 ///
-/// # Errors
+/// Each element of the result is the integral from the first point up to that point, so the first
+/// element is always zero and the last is the [`trapezoidal`] integral of the whole list. This is
+/// the inverse of [`first_order`], up to the constant of integration.
 ///
-/// - Returns [`OutOfBoundsIndexError`] if `index - 1` or `index + 1` is out of bounds in `list`.
-/// - Overlapping `T` values will return a [`f64::NAN`] as their second derivative.
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
-/// # Units
+/// # Errors
 ///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, 2>`. This function only doesn't return that because it would corner the consumer
-/// into providing the order at compile time.
+/// Returns an empty list if `list` is empty.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use sciutil::statistics::derivatives::second_derivative_time_shifted;
+/// # use sciutil::statistics::derivatives;
 /// #
-/// // Normal behavior:
-/// let list = &[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
-/// let derivative =
-///     2.0 * (
-///         (5.0_f64 - 3.0) / (2.0 - 1.0) // f'_(avg,23)
-///         - (3.0 - 1.0) / (1.0 - 0.0)
-///         // f'_(avg,12)
-///     ) / (2.0 - 0.0); // Delta t_13
-///
-/// assert_eq!(
-///     second_derivative_time_shifted(1, list),
-///     Ok((1.0, derivative))
-/// );
-///
-/// // Overlapping values should cause NaN derivatives:
-/// let (independent, derivative) =
-///     second_derivative_time_shifted(1, &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)]).unwrap();
-/// dbg!(independent, derivative);
-///
-/// assert_eq!(independent, 1.0);
-/// assert!(derivative.is_nan());
+/// // Recover position from velocity.
+/// let velocity = [(0.0, 1.0), (1.0, 3.0), (3.0, 3.0)];
+/// let position = derivatives::cumulative_trapezoidal(&velocity);
+/// assert_eq!(*position, [(0.0, 0.0), (1.0, 2.0), (3.0, 8.0)]);
+///
+/// // The derivative of a linear integral recovers the (linear) integrand.
+/// let line = [0.0, 1.0, 2.0, 3.0].map(|t| (t, 2.0));
+/// let integral = derivatives::cumulative_trapezoidal(&line);
+/// assert!(derivatives::first_order(&integral).iter().all(|&(_, v)| v == 2.0));
 /// ```
-pub fn second_derivative_time_shifted<T: Float, F: Float>(
-    index: usize,
-    list: &[(T, F)],
-) -> Result<(T, f64), OutOfBoundsIndexError> {
-    let get = |index: usize| {
-        let (t, f) = oob!(list.get(index));
-        Ok((t.get(), f.get()))
+#[must_use]
+pub fn cumulative_trapezoidal<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
+    let Some((first_t, _)) = list.first() else {
+        return Box::default();
     };
 
-    let (independent_1, dependent_1) = get(oob!(index.checked_sub(1)))?;
-    let (independent_2, dependent_2) = get(index)?;
-    let (independent_3, dependent_3) = get(index + 1)?;
-
-    let derivative_avg_12 = (dependent_2 - dependent_1) / (independent_2 - independent_1);
-    let derivative_avg_23 = (dependent_3 - dependent_2) / (independent_3 - independent_2);
-
-    let delta_independent_13 = independent_3 - independent_1;
+    std::iter::once((T::new(first_t.get()), 0.0))
+        .chain(list.windows(2).scan(0.0, |integral, window| {
+            *integral += trapezoid(&window[0], &window[1]);
 
-    Ok((
-        T::new(independent_2.get()),
-        2.0 * (derivative_avg_23 - derivative_avg_12) / delta_independent_13,
-    ))
+            Some((T::new(window[1].0.get()), *integral))
+        }))
+        .collect()
 }
 
-/// Calculates the numerical second derivative of `F` with respect to `T` using time-shifted data
-/// points.
+/// Calculates the integral of `F` with respect to `T` over the whole list using Simpson's rule.
 ///
-/// - Does not include the first or last data points.
-/// - Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
+/// Simpson's rule fits a parabola to each pair of consecutive intervals, so it is exact for
+/// quadratic data and much more accurate than [`trapezoidal`] on smooth data. The classic form
+/// requires an even number of intervals, so if there is an odd number of intervals, the final
+/// interval is integrated with the trapezoidal rule instead.
 ///
-/// This recognizes that traditional "rise over run" derivatives calculate the average derivative,
-/// at the center of a time interval. This adjusts to calculated derivatives based on midpoints
-/// instead of the start of intervals.
+/// For non-uniformly spaced data, each parabola is fit through its three (unevenly spaced) points
+/// directly, which reduces to the classic `h / 3 * (f[0] + 4 f[1] + f[2])` for uniform spacing.
+/// This is still exact for quadratic data, but it loses accuracy when neighboring intervals differ
+/// greatly in length.
 ///
-/// For details, see the Typst document `/docs/derivatives.typ`. It explains the math further and
-/// derives it. The math is based on William Leonard's article "Dangers of Automated Data
-/// Analysis," pub. _The Physics Teacher,_ vol. 35, April 1996, pp. 220--222.
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
 /// # Errors
 ///
-/// - `list.len() < 3` returns an empty list.
-/// - Overlapping `T` values will return a [`f64::NAN`] as their second derivative.
-///
-/// # Units
-///
-/// If you're interested in properly typing the result, see [`crate::units::Per`]. This could
-/// provide proper typing for the output [`f64`]. Specifically, the most correct typing would be
-/// `Per<F, T, 2>`. This function only doesn't return that because it would corner the consumer
-/// into providing the order at compile time.
+/// - Returns `0.0` if `list.len() < 2`.
+/// - Returns the [`trapezoidal`] integral if `list.len() == 2`.
 ///
 /// # Examples
 ///
-/// Comparing with the actual second derivative:
-///
 /// ```rust
 /// # use sciutil::statistics::derivatives;
 /// #
-/// // `sin(t)` from `t = 0` to `t = 2`.
-/// let list = (0..=10)
-///     .map(|i| {
-///         let t = f64::from(i) * 0.2;
-///         (t, t.sin())
-///     })
-///     .collect::<Box<_>>();
+/// // 20 intervals, from 0 to 2.
+/// let list = (0..=20)
+///     .map(|t| f64::from(t) / 10.0)
+///     .map(|t| (t, t.cos()))
+///     .collect::<Vec<_>>();
+/// assert!((derivatives::simpson(&list) - 2.0_f64.sin()).abs() < 1e-6);
+///
+/// // An odd number of intervals is still accurate.
+/// assert!((derivatives::simpson(&list[..20]) - 1.9_f64.sin()).abs() < 1e-3);
+///
+/// // Much better than the trapezoidal rule.
+/// assert!((derivatives::trapezoidal(&list) - 2.0_f64.sin()).abs() > 1e-4);
+/// ```
+#[must_use]
+pub fn simpson<T: Float, F: Float>(list: &[(T, F)]) -> f64 {
+    let (pairs, remainder) = list.split_at(list.len() - list.len().saturating_sub(1) % 2);
+
+    let parabolas = pairs
+        .windows(3)
+        .step_by(2)
+        .map(|window| {
+            let [(t_0, f_0), (t_1, f_1), (t_2, f_2)] = window else {
+                unreachable!("`windows(3)` always yields three points");
+            };
+
+            let h_0 = t_1.get() - t_0.get();
+            let h_1 = t_2.get() - t_1.get();
+            let sum = h_0 + h_1;
+
+            sum / 6.0
+                * (2.0 - h_1 / h_0).mul_add(
+                    f_0.get(),
+                    (sum.powi(2) / (h_0 * h_1)).mul_add(f_1.get(), (2.0 - h_0 / h_1) * f_2.get()),
+                )
+        })
+        .sum::<f64>();
+
+    // The final interval, if there is an odd number of them.
+    let leftover = match (pairs.last(), remainder) {
+        (Some(start), [end]) => trapezoid(start, end),
+        _ => 0.0,
+    };
+
+    parabolas + leftover
+}
+
+/// Calculates the area of the trapezoid under a pair of points:
+/// `(f_0 + f_1) / 2 * (t_1 - t_0)`.
+fn trapezoid<T: Float, F: Float>((t_0, f_0): &(T, F), (t_1, f_1): &(T, F)) -> f64 {
+    f64::midpoint(f_0.get(), f_1.get()) * (t_1.get() - t_0.get())
+}
+
+/// The product of the units `U` and `V`, as produced by [`integrate_cumulative_typed`].
+pub type ProductUnit<U, V> = UnitList<V, UnitList<U, UnitListNull>>;
+
+/// Calculates the running integral of `F` with respect to `T` using the trapezoidal rule, typing
+/// the result with the product of their units.
 ///
-/// // `-sin(t)` (because `d^2/dx^2 sin(t) = -sin(t)`) from `t = 0` to `t = 2`.
-/// let actual = list.iter().map(|(t, _)| (*t, -t.sin())).collect::<Box<_>>();
-/// assert_eq!(actual.len(), list.len());
+/// Each element of the result is the integral from the first point up to that point, so the first
+/// element is always zero. Each interval contributes `(f[i] + f[i + 1]) / 2 * (t[i + 1] - t[i])`.
 ///
-/// // The time-shifted numerical second derivative.
-/// let result = derivatives::second_order_time_shifted(&list);
-/// assert_eq!(result.len(), actual.len() - 2);
+/// The unit of the result is the product of the unit of the integrand (`U`) and the unit of the
+/// integration variable (`V`). Sciutil's units do not yet cancel, so integrating meters per
+/// second over seconds this way produces "meters per second seconds," rather than meters. See
+/// [`ProductUnit`], or [`integrate_cumulative_per`] for integrating a [`Per`] back into its
+/// numerator.
 ///
-/// // Your data may have more or less error than this!
-/// let accepted_error = 0.005;
-/// // Assert that the two values are within `accepted_error` of each other.
-/// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
-/// for i in 0..result.len() {
-///     let (t, derivative) = actual[i + 1];
-///     let (result_t, result_derivative) = result[i];
+/// # Errors
 ///
-///     // The independent variables should remain unchanged.
-///     eq(t, 0.000_000_000_1, result_t, t);
-///     // The derivatives should be within `accepted_error`.
-///     eq(t, accepted_error, result_derivative, derivative);
-/// }
-/// ```
+/// Returns an empty list if `list` is empty.
 ///
-/// Comparing it to the values calculated by Vernier's Logger Pro®, version 3.16.2:
+/// # Examples
 ///
 /// ```rust
-/// # use sciutil::statistics::derivatives;
+/// # use sciutil::{
+/// #     statistics::derivatives,
+/// #     units::{Float, Meters, Seconds, Unit, composition::Valued},
+/// # };
 /// #
-/// const EXPECTED: [(f64, f64); 13] = [
-///     (0.2, -0.198_007_982_037),
-///     (0.4, -0.388_122_010_68),
-///     (0.6, -0.562_762_839_547),
-///     (0.8, -0.714_968_089_903),
-///     (1.0, -0.838_669_818_726),
-///     (1.2, -0.928_936_428_452),
-///     (1.4, -0.982_169_274_205),
-///     (1.6, -0.996_246_130_409),
-///     (1.8, -0.970_605_797_23),
-///     (2.0, -0.906_270_473_839),
-///     (2.2, -0.805_805_006_559),
-///     (2.4, -0.673_214_636_531),
-///     (2.6, -0.513_785_323_397),
-/// ];
+/// let list = [(0.0, 1.0), (1.0, 3.0), (3.0, 3.0)]
+///     .map(|(t, f)| (Valued::<f64, Seconds>::new(t), Valued::<f64, Meters>::new(f)));
 ///
-/// // `sin(t)` from `t = 0` to `t = 2.8`.
-/// let list = (0..15)
-///     .map(|i| {
-///         let t = f64::from(i) * 0.2;
-///         (t, t.sin())
-///     })
-///     .collect::<Box<_>>();
-/// assert_eq!(list.len(), EXPECTED.len() + 2);
+/// let integral = derivatives::integrate_cumulative_typed(&list);
+/// let values = integral.iter().map(|(_, f)| f.get()).collect::<Vec<_>>();
+/// assert_eq!(values, [0.0, 2.0, 8.0]);
 ///
-/// // The time-shifted numerical second derivative.
-/// let result = derivatives::second_order_time_shifted(&list);
-/// assert_eq!(result.len(), EXPECTED.len());
+/// assert_eq!(integral[0].1.unit().symbol(), "m s");
+/// ```
+#[must_use]
+#[expect(
+    clippy::type_complexity,
+    reason = "`ProductUnit` already names the unit, the rest is the `(T, F)` pairs used throughout"
+)]
+pub fn integrate_cumulative_typed<U: Unit + Default, V: Unit + Default>(
+    list: &[(Valued<f64, V>, Valued<f64, U>)],
+) -> Box<[(Valued<f64, V>, Valued<f64, ProductUnit<U, V>>)]> {
+    cumulative_trapezoidal(list)
+        .iter()
+        .map(|(t, integral)| (Valued::new(t.get()), Valued::new(*integral)))
+        .collect()
+}
+
+/// Calculates the running integral of a [`Per<F, T, 1>`][`Per`] with respect to `T` using the
+/// trapezoidal rule, typing the result as `F`.
 ///
-/// let effectively_equal = 0.000_000_000_1;
-/// // Assert that the two values are within `error` of each other.
-/// let eq = |t, error, a: f64, b: f64| assert!((a - b).abs() <= error, "{a} != {b} @ {t}");
+/// This undoes [`first_order_per`]: integrating meters per second over seconds produces meters.
+/// See [`cumulative_trapezoidal`] for details.
 ///
-/// for i in 0..result.len() {
-///     let (t, derivative) = EXPECTED[i];
-///     let (result_t, result_derivative) = result[i];
+/// Assumes that the list is sorted by ascending `T` values (smallest first, largest last).
 ///
-///     // The independent variables should remain unchanged.
-///     eq(t, effectively_equal, result_t, t);
-///     // The derivatives should be effectively equal.
-///     eq(t, effectively_equal, result_derivative, derivative);
-/// }
-/// ```
+/// # Errors
 ///
-/// Overlapping values cause NaN second derivatives:
+/// Returns an empty list if `list` is empty.
+///
+/// # Examples
 ///
 /// ```rust
-/// # use sciutil::statistics::derivatives;
+/// # use sciutil::{
+/// #     statistics::derivatives,
+/// #     units::{Float, FloatDisplay, Meters, Per, Seconds, composition::Valued},
+/// # };
 /// #
-/// let list = &[(1.0, 1.0), (1.0, 3.0), (1.0, 5.0)];
-/// let result = derivatives::second_order_time_shifted(list);
-/// assert_eq!(list.len() - 2, result.len());
+/// type Velocity = Per<Valued<f64, Meters>, Valued<f64, Seconds>, 1>;
 ///
-/// for (independent, derivative) in result {
-///     assert_eq!(independent, 1.0);
-///     assert!(derivative.is_nan());
-/// }
+/// let list = [(0.0, 1.0), (1.0, 3.0), (3.0, 3.0)]
+///     .map(|(t, v)| (Valued::<f64, Seconds>::new(t), Velocity::new(v)));
+///
+/// let position = derivatives::integrate_cumulative_per(&list);
+/// let values = position.iter().map(|(_, x)| x.get()).collect::<Vec<_>>();
+/// assert_eq!(values, [0.0, 2.0, 8.0]);
+///
+/// assert_eq!(Valued::<f64, Meters>::symbol().as_deref(), Some("m"));
 /// ```
 #[must_use]
-#[expect(clippy::missing_panics_doc, reason = "see `expect` string")]
-pub fn second_order_time_shifted<T: Float, F: Float>(list: &[(T, F)]) -> Box<[(T, f64)]> {
-    if list.len() < 3 {
-        return Box::default();
-    }
-
-    let mut derivative = Vec::with_capacity(list.len() - 2);
-
-    // Skips the first and last index.
-    for i in 1..(list.len() - 1) {
-        derivative.push(
-            second_derivative_time_shifted(i, list)
-                .expect("`1 < i < list.len() - 1`, this is safe"),
-        );
-    }
-
-    derivative.into_boxed_slice()
+pub fn integrate_cumulative_per<T: Float, F: Float>(list: &[(T, Per<F, T, 1>)]) -> Box<[(T, F)]> {
+    cumulative_trapezoidal(list)
+        .iter()
+        .map(|(t, integral)| (T::new(t.get()), F::new(*integral)))
+        .collect()
 }
@@ -109,3 +109,629 @@ fn second_time_shifted() {
     eq(independent, 1.0);
     assert!(derivative.is_nan());
 }
+
+#[test]
+fn second_order_one_sided() {
+    // `f(t) = t^2 + 3t`, so `f'(t) = 2t + 3`. Non-uniform spacing.
+    let f = |t: f64| t.mul_add(t, 3.0 * t);
+    let list = [0.5, 1.0, 2.5, 3.0, 4.5].map(|t| (t, f(t)));
+
+    // The three-point difference is exact for quadratics, the two-point difference is not.
+    let (t, two_point) = super::forward_difference_derivative(0, &list).unwrap();
+    let (_, three_point) = super::forward_difference_second_order(0, &list).unwrap();
+    eq(t, 0.5);
+    assert!((three_point - 2.0f64.mul_add(t, 3.0)).abs() < 10e-12);
+    assert!((two_point - 2.0f64.mul_add(t, 3.0)).abs() > 0.1);
+
+    let (t, two_point) = super::backward_difference_derivative(4, &list).unwrap();
+    let (_, three_point) = super::backward_difference_second_order(4, &list).unwrap();
+    eq(t, 4.5);
+    assert!((three_point - 2.0f64.mul_add(t, 3.0)).abs() < 10e-12);
+    assert!((two_point - 2.0f64.mul_add(t, 3.0)).abs() > 0.1);
+
+    // Three points are required.
+    assert!(super::forward_difference_second_order(3, &list).is_err());
+    assert!(super::backward_difference_second_order(1, &list).is_err());
+}
+
+#[test]
+fn both_uncertain() {
+    use crate::units::UncertainFloat;
+
+    // Only the independent variable has any uncertainty.
+    let list = &[
+        (UncertainFloat::new(0.0, 0.1), UncertainFloat::new(1.0, 0.0)),
+        (UncertainFloat::new(1.0, 0.1), UncertainFloat::new(3.0, 0.0)),
+        (UncertainFloat::new(2.0, 0.1), UncertainFloat::new(5.0, 0.0)),
+    ];
+    let result = super::first_order_both_uncertain(list);
+    assert_eq!(result.len(), list.len());
+
+    for (index, (t, derivative)) in result.iter().enumerate() {
+        eq(*t, *list[index].0.value());
+        eq(*derivative.value(), 2.0);
+    }
+
+    // The forward and backward differences span `Delta t = 1`: `2 * sqrt(0.1^2 + 0.1^2) / 1`.
+    eq(*result[0].1.uncertainty(), 2.0 * 0.02_f64.sqrt());
+    eq(*result[2].1.uncertainty(), 2.0 * 0.02_f64.sqrt());
+    // The central difference spans `Delta t = 2`: `2 * sqrt(0.1^2 + 0.1^2) / 2`.
+    eq(*result[1].1.uncertainty(), 0.02_f64.sqrt());
+
+    assert!(super::first_order_both_uncertain(&list[..1]).is_empty());
+}
+
+#[test]
+fn differentiate_dispatch() {
+    use super::DerivativeMethod;
+
+    // `sin(t)` from `t = 0` to `t = 2`.
+    let list = (0..=10)
+        .map(|i| {
+            let t = f64::from(i) * 0.2;
+            (t, t.sin())
+        })
+        .collect::<Box<_>>();
+
+    assert_eq!(
+        super::differentiate(&list, DerivativeMethod::FiniteDifference),
+        super::first_order(&list)
+    );
+    assert_eq!(
+        super::differentiate(&list, DerivativeMethod::TimeShifted),
+        super::first_order_time_shifted(&list)
+    );
+    assert_eq!(
+        super::differentiate(&list, DerivativeMethod::FivePoint),
+        super::first_order_five_point(&list)
+    );
+    assert_eq!(
+        super::differentiate(&list, DerivativeMethod::Richardson),
+        super::first_order_richardson(&list)
+    );
+    assert_eq!(
+        DerivativeMethod::default(),
+        DerivativeMethod::FiniteDifference
+    );
+}
+
+#[test]
+fn five_point_and_richardson() {
+    // `sin(t)` from `t = 0` to `t = 2`.
+    let list = (0..=10)
+        .map(|i| {
+            let t = f64::from(i) * 0.2;
+            (t, t.sin())
+        })
+        .collect::<Box<_>>();
+    let first_order = super::first_order(&list);
+
+    for (result, algorithm) in [
+        (super::first_order_five_point(&list), "five-point"),
+        (super::first_order_richardson(&list), "richardson"),
+    ] {
+        assert_eq!(result.len(), list.len());
+
+        // The ends fall back on the traditional algorithms.
+        for index in [0, 1, list.len() - 2, list.len() - 1] {
+            assert_eq!(result[index], first_order[index], "{algorithm} @ {index}");
+        }
+
+        // Uniform spacing, so both should be far more accurate than central differences.
+        for index in 2..(list.len() - 2) {
+            let (t, derivative) = result[index];
+            let error = (derivative - t.cos()).abs();
+            assert!(error < 0.000_1, "{algorithm} @ {index}: {error}");
+            assert!(error < (first_order[index].1 - t.cos()).abs());
+        }
+    }
+
+    assert!(super::first_order_five_point::<f64, f64>(&[]).is_empty());
+    assert_eq!(
+        super::first_order_richardson(&list[..2]),
+        super::first_order(&list[..2])
+    );
+}
+
+#[test]
+fn local_polynomial() {
+    // `f(t) = t^3 - 2t^2 + 5`, so `f'(t) = 3t^2 - 4t`. Non-uniform spacing.
+    let f = |t: f64| (t - 2.0).mul_add(t.powi(2), 5.0);
+    let f_prime = |t: f64| 3.0f64.mul_add(t, -4.0) * t;
+    let list = [-1.5, -1.0, -0.2, 0.0, 0.5, 1.1, 1.3, 2.0, 2.8, 3.0].map(|t| (t, f(t)));
+
+    // A cubic fit recovers the derivative exactly, even at the ends.
+    let result = super::local_polynomial_derivative(&list, 5, 3);
+    assert_eq!(result.len(), list.len());
+    for (index, (t, derivative)) in result.iter().enumerate() {
+        eq(*t, list[index].0);
+        assert!(
+            (derivative - f_prime(*t)).abs() < 10e-10,
+            "{derivative} != {} @ {t}",
+            f_prime(*t)
+        );
+    }
+
+    // A quadratic fit does not.
+    let result = super::local_polynomial_derivative(&list, 5, 2);
+    assert!(
+        result
+            .iter()
+            .any(|(t, derivative)| (derivative - f_prime(*t)).abs() > 0.01)
+    );
+
+    // Invalid parameters.
+    assert!(super::local_polynomial_derivative(&list, 3, 3).is_empty());
+    assert!(super::local_polynomial_derivative(&list, 3, 0).is_empty());
+    assert!(super::local_polynomial_derivative(&list, 11, 3).is_empty());
+
+    // Overlapping values.
+    let result = super::local_polynomial_derivative(&[(1.0, 1.0), (1.0, 2.0), (1.0, 3.0)], 3, 1);
+    assert!(result.iter().all(|(_, derivative)| derivative.is_nan()));
+}
+
+#[test]
+fn time_shifted_valued() {
+    use crate::units::{
+        Meters, Seconds, Unit,
+        composition::{Power, UnitList, UnitListNull},
+    };
+
+    type Velocity = UnitList<Meters, UnitList<Power<Seconds, -1>, UnitListNull>>;
+
+    let position = [(0.0, 0.0), (1.0, 1.0), (2.0, 4.0), (3.0, 9.0), (4.0, 16.0)];
+    let velocity = super::first_order_time_shifted_valued::<_, _, Velocity>(&position);
+    let untyped = super::first_order_time_shifted(&position);
+
+    assert_eq!(velocity.len(), untyped.len());
+    for ((t, typed), (untyped_t, untyped)) in velocity.iter().zip(untyped) {
+        eq(*t, untyped_t);
+        eq(*typed.value(), untyped);
+        assert_eq!(typed.unit().symbol(), "s^(-1) m");
+    }
+}
+
+#[test]
+fn smoothing_window() {
+    let clean = (0..100)
+        .map(|i| {
+            let t = f64::from(i) * 0.05;
+            (t, t.sin())
+        })
+        .collect::<Box<_>>();
+    // Deterministic pseudo-noise of a given amplitude.
+    let noisy = |amplitude: f64| {
+        clean
+            .iter()
+            .zip(0_u32..)
+            .map(|(&(t, f), i)| (t, f + amplitude * f64::from((i * 7919) % 13) / 13.0))
+            .collect::<Box<_>>()
+    };
+
+    let clean_window = super::suggest_smoothing_window(&clean);
+    let slightly_noisy_window = super::suggest_smoothing_window(&noisy(0.2));
+    let very_noisy_window = super::suggest_smoothing_window(&noisy(1.0));
+
+    // Smooth data needs no more than the minimum window.
+    assert_eq!(clean_window, 3);
+    assert!(clean_window < slightly_noisy_window);
+    assert!(slightly_noisy_window < very_noisy_window);
+    assert!(very_noisy_window <= clean.len());
+    for window in [clean_window, slightly_noisy_window, very_noisy_window] {
+        assert_eq!(window % 2, 1);
+    }
+
+    assert_eq!(
+        super::suggest_smoothing_window(&[(0.0, 1.0), (1.0, 2.0)]),
+        2
+    );
+    assert_eq!(super::suggest_smoothing_window(&[(0.0, 1.0); 5]), 3);
+}
+
+#[test]
+fn trapezoidal() {
+    // `f(t) = 3t^2`, so the integral from 0 is `t^3`.
+    let list = (0..=100)
+        .map(|t| f64::from(t) / 50.0)
+        .map(|t| (t, 3.0 * t * t))
+        .collect::<Box<_>>();
+
+    let cumulative = super::cumulative_trapezoidal(&list);
+    assert_eq!(cumulative.len(), list.len());
+    eq(cumulative[0].1, 0.0);
+    for ((t, integral), (list_t, _)) in cumulative.iter().zip(&list) {
+        eq(*t, *list_t);
+        assert!((integral - t.powi(3)).abs() < 1e-3, "{t}: {integral}");
+    }
+
+    // The last element of the running integral is the whole integral.
+    eq(
+        super::trapezoidal(&list),
+        cumulative[cumulative.len() - 1].1,
+    );
+
+    eq(super::trapezoidal::<f64, f64>(&[]), 0.0);
+    eq(super::trapezoidal(&[(1.0, 5.0)]), 0.0);
+    assert!(super::cumulative_trapezoidal::<f64, f64>(&[]).is_empty());
+    assert_eq!(*super::cumulative_trapezoidal(&[(1.0, 5.0)]), [(1.0, 0.0)]);
+}
+
+#[test]
+fn second_order() {
+    // Exact for a quadratic, even with non-uniform spacing: the second derivative of `3t^2 - t` is
+    // `6`.
+    let list = [0.0, 0.3, 1.0, 1.2, 2.0, 3.5].map(|t: f64| (t, 3.0_f64.mul_add(t.powi(2), -t)));
+    let result = super::second_order(&list);
+    assert_eq!(result.len(), list.len());
+    for &(t, derivative) in &result {
+        assert!((derivative - 6.0).abs() < 1e-9, "{derivative} @ {t}");
+    }
+
+    // Uniform spacing reduces to the classic stencils, with `h^2 = 0.25`.
+    let list = [(0.0, 1.0), (0.5, 2.0), (1.0, 5.0), (1.5, 3.0), (2.0, 7.0)];
+    let result = super::second_order(&list);
+    // `(2 * 1 - 5 * 2 + 4 * 5 - 3) / 0.25`
+    eq(result[0].1, 36.0);
+    // `(2 - 2 * 5 + 3) / 0.25`
+    eq(result[2].1, -20.0);
+    // `(2 * 7 - 5 * 3 + 4 * 5 - 2) / 0.25`
+    eq(result[4].1, 68.0);
+
+    // Three points share a single parabola.
+    let result = super::second_order(&list[..3]);
+    assert!(
+        result
+            .iter()
+            .all(|&(_, derivative)| (derivative - 8.0).abs() < 1e-9)
+    );
+
+    assert!(super::second_order(&list[..2]).is_empty());
+}
+
+#[test]
+fn savitzky_golay() {
+    // Polynomials up to `poly_order` pass through unchanged, even at the ends.
+    let cubic = (0..15)
+        .map(|i| {
+            let t = f64::from(i) * 0.5;
+            (t, 0.5_f64.mul_add(t.powi(3), -t) + 2.0)
+        })
+        .collect::<Box<_>>();
+    let smoothed = super::savitzky_golay(&cubic, 5, 3);
+    for (&(t, smooth), &(list_t, exact)) in smoothed.iter().zip(&cubic) {
+        eq(t, list_t);
+        assert!((smooth - exact).abs() < 1e-9, "{smooth} != {exact} @ {t}");
+    }
+
+    // A zeroth order filter is a moving average in the interior.
+    let list = [(0.0, 1.0), (1.0, 4.0), (2.0, 1.0), (3.0, 4.0), (4.0, 1.0)];
+    let smoothed = super::savitzky_golay(&list, 3, 0);
+    eq(smoothed[1].1, 2.0);
+    eq(smoothed[2].1, 3.0);
+
+    // An even window is widened, and an order that's too large is clamped.
+    assert_eq!(
+        super::savitzky_golay(&list, 2, 5),
+        super::savitzky_golay(&list, 3, 2),
+    );
+
+    assert!(super::savitzky_golay::<f64, f64>(&[], 5, 2).is_empty());
+}
+
+#[test]
+fn simpson() {
+    // Exact for a quadratic, even with non-uniform spacing: the integral of `3t^2` is `t^3`.
+    let list = [0.0, 0.25, 1.0, 1.5, 3.0].map(|t| (t, 3.0 * t * t));
+    eq(super::simpson(&list), 27.0);
+
+    // With an odd number of intervals, the last one falls back to the trapezoidal rule.
+    let line = [0.0, 1.0, 2.0, 3.0].map(|t| (t, 2.0 * t));
+    eq(super::simpson(&line), 9.0);
+    eq(super::simpson(&line[..2]), super::trapezoidal(&line[..2]));
+
+    eq(super::simpson::<f64, f64>(&[]), 0.0);
+    eq(super::simpson(&[(1.0, 5.0)]), 0.0);
+}
+
+#[test]
+fn integrate_typed() {
+    use crate::units::{
+        Float, Meters, Seconds, Unit,
+        composition::{Power, UnitList, UnitListNull, Valued},
+    };
+
+    type Velocity = UnitList<Meters, UnitList<Power<Seconds, -1>, UnitListNull>>;
+
+    // `v(t) = 2t`, so the integral is `t^2`, which the trapezoidal rule gets exactly for a line.
+    let list = [0.0, 0.5, 1.0, 2.0, 4.0].map(|t| {
+        (
+            Valued::<f64, Seconds>::new(t),
+            Valued::<f64, Velocity>::new(2.0 * t),
+        )
+    });
+
+    let integral = super::integrate_cumulative_typed(&list);
+    assert_eq!(integral.len(), list.len());
+    for ((t, position), (list_t, _)) in integral.iter().zip(&list) {
+        eq(t.get(), list_t.get());
+        eq(position.get(), t.get().powi(2));
+
+        // The product of the integrand and integration variable's units.
+        assert_eq!(
+            position.unit().symbol(),
+            format!("{} {}", Velocity::default().symbol(), Seconds.symbol())
+        );
+    }
+
+    assert!(super::integrate_cumulative_typed::<Meters, Seconds>(&[]).is_empty());
+}
+
+#[test]
+fn with_error() {
+    // `e^t` from `t = 0` to `t = 1`.
+    let list = (0..=50)
+        .map(|i| {
+            let t = f64::from(i) * 0.02;
+            (t, t.exp())
+        })
+        .collect::<Box<_>>();
+
+    let result = super::first_order_with_error(&list);
+    assert_eq!(result.len(), list.len());
+
+    for (index, (t, derivative)) in result.iter().enumerate() {
+        let error = (derivative.value() - t.exp()).abs();
+        assert!(
+            error <= *derivative.uncertainty(),
+            "{error} > {derivative} @ {t}"
+        );
+
+        // Smooth data gives small errors, especially in the middle.
+        let max_uncertainty = if (2..list.len() - 2).contains(&index) {
+            10e-4
+        } else {
+            10e-2
+        };
+        assert!(
+            *derivative.uncertainty() < max_uncertainty,
+            "{derivative} @ {t}"
+        );
+    }
+
+    assert!(super::first_order_with_error(&list[..2]).is_empty());
+}
+
+#[test]
+fn wrapped() {
+    use super::first_order_wrapped;
+
+    // A sawtooth angle in radians, rotating at 2 rad/s and wrapping at `TAU`.
+    let period = std::f64::consts::TAU;
+    let list = (0..20)
+        .map(|i| {
+            let t = f64::from(i) * 0.5;
+            (t, (t * 2.0).rem_euclid(period))
+        })
+        .collect::<Box<_>>();
+    assert!(list.windows(2).any(|w| w[1].1 < w[0].1), "never wraps");
+
+    let result = first_order_wrapped(&list, period);
+    assert_eq!(result.len(), list.len());
+
+    for (&(t, _), &(result_t, rate)) in list.iter().zip(&result) {
+        eq(t, result_t);
+        assert!((rate - 2.0).abs() < 1e-12, "{rate} != 2 @ {t}");
+    }
+
+    // Rotating the other way wraps downwards.
+    let reversed = list
+        .iter()
+        .map(|&(t, f)| (t, period - f))
+        .collect::<Box<_>>();
+    for (_, rate) in first_order_wrapped(&reversed, period) {
+        assert!((rate + 2.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn vector() {
+    use super::first_order_vec;
+
+    // A circle of radius 2: `(2 cos(t), 2 sin(t))`, with derivative `(-2 sin(t), 2 cos(t))`.
+    let list = (0..=100)
+        .map(|i| {
+            let t = f64::from(i) * 0.01;
+            (t, [2.0 * t.cos(), 2.0 * t.sin()])
+        })
+        .collect::<Box<_>>();
+
+    let result = first_order_vec(&list);
+    assert_eq!(result.len(), list.len());
+
+    for (index, (t, [x, y])) in result.iter().copied().enumerate() {
+        eq(t, list[index].0);
+
+        // One-sided differences at the ends are less accurate.
+        let accepted_error = if index == 0 || index == list.len() - 1 {
+            0.01
+        } else {
+            0.0001
+        };
+        let (expected_x, expected_y) = (-2.0 * t.sin(), 2.0 * t.cos());
+        assert!(
+            (x - expected_x).abs() < accepted_error,
+            "{x} != {expected_x} @ {t}"
+        );
+        assert!(
+            (y - expected_y).abs() < accepted_error,
+            "{y} != {expected_y} @ {t}"
+        );
+    }
+
+    assert!(first_order_vec::<f64, 2>(&[(0.0, [1.0, 2.0])]).is_empty());
+}
+
+#[test]
+fn dedup() {
+    use super::{first_order, first_order_dedup};
+
+    // `f = 3t`, sampled with some timestamps repeated.
+    let list = &[
+        (0.0, 0.0),
+        (0.0, 0.0),
+        (1.0, 2.9),
+        (1.0, 3.1),
+        (1.0, 3.0),
+        (2.0, 6.0),
+        (3.0, 9.0),
+        (3.0, 9.0),
+    ];
+    assert!(first_order(list).iter().any(|(_, f)| !f.is_finite()));
+
+    let result = first_order_dedup(list);
+    assert_eq!(result.len(), 4);
+
+    for ((t, derivative), expected_t) in result.iter().zip([0.0, 1.0, 2.0, 3.0]) {
+        eq(*t, expected_t);
+        assert!(derivative.is_finite());
+        assert!((derivative - 3.0).abs() < 1e-12, "{derivative} @ {t}");
+    }
+
+    assert!(first_order_dedup(&[(1.0, 1.0), (1.0, 2.0)]).is_empty());
+}
+
+#[test]
+fn radius_of_curvature() {
+    use super::radius_of_curvature;
+
+    // The upper half of a circle of radius 3 centered at the origin, away from the vertical edges.
+    let radius = 3.0;
+    let list = (-200..=200)
+        .map(|i| {
+            let t = f64::from(i) * 0.01;
+            (t, (radius * radius - t * t).sqrt())
+        })
+        .collect::<Box<_>>();
+
+    let result = radius_of_curvature(&list);
+    assert_eq!(result.len(), list.len() - 2);
+
+    for (t, result) in result {
+        assert!(
+            (result - radius).abs() < 0.001,
+            "{result} != {radius} @ {t}"
+        );
+    }
+}
+
+#[test]
+fn moving_average() {
+    use super::moving_average_pairs;
+
+    // A constant signal with alternating noise.
+    let list = (0..100)
+        .map(|i| {
+            let noise = if i % 2 == 0 { 1.0 } else { -1.0 };
+            (f64::from(i) * 0.1, 5.0 + noise)
+        })
+        .collect::<Box<_>>();
+
+    let variance = |list: &[(f64, f64)]| {
+        let values = list.iter().map(|&(_, f)| f).collect::<Box<_>>();
+        crate::statistics::stddev(&values).powi(2)
+    };
+
+    let smoothed = moving_average_pairs(&list, 5);
+    assert_eq!(smoothed.len(), list.len());
+    for (&(t, _), &(smoothed_t, _)) in list.iter().zip(&smoothed) {
+        eq(t, smoothed_t);
+    }
+    assert!(variance(&smoothed) < variance(&list) / 10.0);
+
+    // Small windows do nothing.
+    for window in [0, 1] {
+        assert_eq!(&*moving_average_pairs(&list, window), &*list);
+    }
+    assert!(moving_average_pairs::<f64, f64>(&[], 5).is_empty());
+}
+
+#[test]
+fn resample_and_first_order() {
+    // `sin(t)`, densely sampled at irregular intervals from `t = 0` to `t = 3`.
+    let list: Box<[(f64, f64)]> = (0..=60)
+        .map(|i| {
+            let t = f64::from(i) * 0.05;
+            // Jitter every other point.
+            let t = if i % 2 == 1 { t + 0.02 } else { t };
+            (t, t.sin())
+        })
+        .collect();
+
+    let resampled = super::resample_and_first_order(&list, 61);
+    let non_uniform = super::first_order(&list);
+    assert_eq!(resampled.len(), non_uniform.len());
+
+    // Both should track `cos(t)`, and each other, away from the ends.
+    for (&(t, resampled), &(_, non_uniform)) in resampled
+        .iter()
+        .zip(&non_uniform)
+        .take(resampled.len() - 2)
+        .skip(2)
+    {
+        assert!((resampled - t.cos()).abs() < 0.01, "{resampled} @ {t}");
+        assert!(
+            (resampled - non_uniform).abs() < 0.05,
+            "{resampled} != {non_uniform} @ {t}"
+        );
+    }
+}
+
+#[test]
+fn monotonic_segments() {
+    // `sin(t)` from `t = 0` to `t = 6.2`, which peaks at `t = 1.6` and bottoms out at `t = 4.7`.
+    let list: Box<[(f64, f64)]> = (0..=62)
+        .map(|i| {
+            let t = f64::from(i) * 0.1;
+            (t, t.sin())
+        })
+        .collect();
+
+    let segments = super::monotonic_segments(&list);
+    assert_eq!(*segments, [(0, 16), (16, 47), (47, 62)]);
+
+    // Up, down, up.
+    for (index, &(start, end)) in segments.iter().enumerate() {
+        let rising = index % 2 == 0;
+        assert_eq!(list[end].1 > list[start].1, rising, "{start}..={end}");
+    }
+
+    assert!(super::monotonic_segments::<f64, f64>(&[]).is_empty());
+    assert_eq!(
+        *super::monotonic_segments(&[(0.0, 2.0), (1.0, 2.0), (2.0, 2.0)]),
+        [(0, 2)]
+    );
+}
+
+#[test]
+fn central_differences_interior() {
+    // Irregularly spaced `sin(t)`.
+    let list = [0.0, 0.1, 0.3, 0.35, 0.6, 1.0, 1.1].map(|t: f64| (t, t.sin()));
+
+    let result = super::central_differences_interior(&list);
+    assert_eq!(result.len(), list.len() - 2);
+
+    for (index, &(t, derivative)) in result.iter().enumerate() {
+        let (expected_t, expected) =
+            super::central_difference_derivative(index + 1, &list).unwrap();
+
+        eq(t, expected_t);
+        eq(derivative, expected);
+    }
+
+    // Matches the interior of `first_order`.
+    let first_order = super::first_order(&list);
+    assert_eq!(*result, first_order[1..first_order.len() - 1]);
+
+    assert!(super::central_differences_interior(&list[..2]).is_empty());
+    assert!(super::central_differences_interior::<f64, f64>(&[]).is_empty());
+}
@@ -12,7 +12,10 @@ pub mod derivatives;
 
 use std::ops::Div;
 
-use crate::units::Float;
+use crate::units::{Float, UncertainFloat};
+
+#[cfg(any(feature = "serde", test))]
+use serde::{Deserialize, Serialize};
 
 /// Computes the mean of a list of values.
 ///
@@ -43,9 +46,337 @@ pub fn mean<F: Float>(list: &[F]) -> F {
         .into()
 }
 
-/// Computes the corrected sample standard distribution of a list of values.
+/// Computes the weighted mean of a list of values: `sum_n (w[n] * x[n]) / sum_n w[n]`.
+///
+/// `values[n]` is weighted by `weights[n]`, so values with larger weights have more influence on
+/// the result. See [`weighted_mean_from_uncertain`] to weight measurements by their uncertainties.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `values.len() != weights.len()`.
+/// - Returns a [`f64::NAN`] if the weights sum to zero, including if `values.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{mean, weighted_mean};
+/// #
+/// let values = [2.0, 3.0, 5.0];
+///
+/// // Equal weights reproduce the plain mean.
+/// assert_eq!(weighted_mean(&values, &[1.0, 1.0, 1.0]), mean(&values));
+/// assert_eq!(weighted_mean(&values, &[2.0, 1.0, 1.0]), 3.0);
+///
+/// assert!(weighted_mean(&values, &[1.0, 1.0]).is_nan());
+/// assert!(weighted_mean(&values, &[0.0, 0.0, 0.0]).is_nan());
+/// assert!(weighted_mean::<f64>(&[], &[]).is_nan());
+/// ```
+#[must_use]
+pub fn weighted_mean<F: Float>(values: &[F], weights: &[F]) -> F {
+    if values.len() != weights.len() {
+        return F::new(f64::NAN);
+    }
+
+    let (weighted_sum, weight_sum) = values.iter().zip(weights).fold(
+        (0.0, 0.0),
+        |(weighted_sum, weight_sum), (value, weight)| {
+            (
+                weight.get().mul_add(value.get(), weighted_sum),
+                weight_sum + weight.get(),
+            )
+        },
+    );
+
+    if weight_sum == 0.0 {
+        return F::new(f64::NAN);
+    }
+
+    F::new(weighted_sum / weight_sum)
+}
+
+/// Computes the [`weighted_mean`] of a list of measurements, weighting each by the inverse square
+/// of its uncertainty (`1 / σ^2`).
+///
+/// This is the standard way to combine several measurements of the same quantity, where more
+/// precise measurements (those with smaller uncertainties) count for more.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `list.len() == 0`.
+/// - Returns a [`f64::NAN`] if any uncertainty is zero, because its weight is infinite.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{
+/// #     statistics::{mean, weighted_mean_from_uncertain},
+/// #     units::UncertainFloat,
+/// # };
+/// #
+/// // Equal uncertainties reproduce the plain mean.
+/// let equal = [UncertainFloat::new(2.0, 0.5), UncertainFloat::new(4.0, 0.5)];
+/// assert_eq!(weighted_mean_from_uncertain(&equal), mean(&[2.0, 4.0]));
+///
+/// // The first measurement is twice as precise, so it has four times the weight.
+/// let unequal = [UncertainFloat::new(2.0, 0.5), UncertainFloat::new(7.0, 1.0)];
+/// assert_eq!(weighted_mean_from_uncertain(&unequal), 3.0);
+///
+/// assert!(weighted_mean_from_uncertain::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn weighted_mean_from_uncertain<F: Float>(list: &[UncertainFloat<F>]) -> F {
+    let values = list
+        .iter()
+        .map(|measurement| measurement.value().get())
+        .collect::<Box<_>>();
+    let weights = list
+        .iter()
+        .map(|measurement| measurement.uncertainty().get().powi(-2))
+        .collect::<Box<_>>();
+
+    F::new(weighted_mean(&values, &weights))
+}
+
+/// Finds the smallest value in a list of values.
+///
+/// Values are compared with [`f64::total_cmp`], so NaN values don't cause a panic. Instead, they
+/// are ordered deterministically: a (positive) NaN is larger than every other value, and a
+/// negative NaN is smaller.
+///
+/// # Errors
+///
+/// Returns [`None`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::min;
+/// #
+/// assert_eq!(min(&[3.0, -1.5, 2.0]), Some(-1.5));
+/// assert_eq!(min(&[3.0, f64::NAN, 2.0]), Some(2.0));
+/// assert_eq!(min::<f64>(&[]), None);
+/// ```
+#[must_use]
+pub fn min<F: Float>(list: &[F]) -> Option<F> {
+    list.iter()
+        .map(Float::get)
+        .min_by(f64::total_cmp)
+        .map(F::new)
+}
+
+/// Finds the largest value in a list of values.
+///
+/// Values are compared with [`f64::total_cmp`], so NaN values don't cause a panic. Instead, they
+/// are ordered deterministically: a (positive) NaN is larger than every other value, and a
+/// negative NaN is smaller.
+///
+/// # Errors
+///
+/// Returns [`None`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::max;
+/// #
+/// assert_eq!(max(&[3.0, -1.5, 2.0]), Some(3.0));
+/// assert!(max(&[3.0, f64::NAN, 2.0]).unwrap().is_nan());
+/// assert_eq!(max::<f64>(&[]), None);
+/// ```
+#[must_use]
+pub fn max<F: Float>(list: &[F]) -> Option<F> {
+    list.iter()
+        .map(Float::get)
+        .max_by(f64::total_cmp)
+        .map(F::new)
+}
+
+/// Computes the range of a list of values: the difference between the [`max`] and the [`min`].
+///
+/// # Errors
+///
+/// - Returns [`None`] if `list.len() == 0`.
+/// - Returns a [`f64::NAN`] if `list` contains a NaN value, see [`min`] and [`max`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::range;
+/// #
+/// assert_eq!(range(&[3.0, -1.5, 2.0]), Some(4.5));
+/// assert_eq!(range(&[2.0]), Some(0.0));
+/// assert!(range(&[3.0, f64::NAN, 2.0]).unwrap().is_nan());
+/// assert_eq!(range::<f64>(&[]), None);
+/// ```
+#[must_use]
+pub fn range<F: Float>(list: &[F]) -> Option<F> {
+    Some(F::new(max(list)?.get() - min(list)?.get()))
+}
+
+/// Computes the median of a list of values: the middle value, or the mean of the two middle values
+/// if `list` has an even length.
+///
+/// Unlike the [`mean`], this is robust to outliers. This allocates a sorted copy of `list`, ordered
+/// by [`f64::total_cmp`] so that NaN values don't cause a panic.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::median;
+/// #
+/// assert_eq!(median(&[4.0, 2.0, 3.0]), 3.0);
+/// assert_eq!(median(&[4.0, 2.0, 3.0, 100.0]), 3.5);
+/// assert_eq!(median(&[2.0]), 2.0);
+/// assert!(median::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn median<F: Float>(list: &[F]) -> F {
+    F::new(quantile_of_sorted(&sorted_values(list), 0.5))
+}
+
+/// Computes the median absolute deviation of a list of values: the [`median`] of the absolute
+/// deviations from the [`median`], `median(|x - median(x)|)`.
+///
+/// Like the [`stddev`], this is a measure of how spread out the data is, but it is far less
+/// sensitive to outliers. See [`mad_normalized`] to compare it to a standard deviation.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{median_absolute_deviation, stddev};
+/// #
+/// // Most of the data is around 10, with a single gross outlier.
+/// let list = [9.8, 10.1, 9.9, 10.0, 10.2, 9.7, 10.3, 1_000.0];
+///
+/// assert!((median_absolute_deviation(&list) - 0.2).abs() < 0.000_000_000_1);
+/// assert!(stddev(&list) > 300.0);
+///
+/// assert!(median_absolute_deviation::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn median_absolute_deviation<F: Float>(list: &[F]) -> F {
+    let center = median(list).get();
+    let deviations = list
+        .iter()
+        .map(|value| (value.get() - center).abs())
+        .collect::<Box<_>>();
+
+    median(&deviations).into()
+}
+
+/// Computes the [`median_absolute_deviation`] of a list of values, scaled by `1.4826` so that it
+/// estimates the [`stddev`] of normally distributed data.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{mad_normalized, median_absolute_deviation};
+/// #
+/// let list = [9.8, 10.1, 9.9, 10.0, 10.2, 9.7, 10.3, 1_000.0];
+///
+/// assert_eq!(mad_normalized(&list), 1.4826 * median_absolute_deviation(&list));
+/// assert!(mad_normalized::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn mad_normalized<F: Float>(list: &[F]) -> F {
+    /// `1 / Φ^(-1)(3/4)`, the reciprocal of the third quartile of the standard normal distribution.
+    const NORMAL_CONSISTENCY: f64 = 1.4826;
+
+    (NORMAL_CONSISTENCY * median_absolute_deviation(list).get()).into()
+}
+
+/// Computes the corrected sample variance of a list of values.
 ///
 /// In a somewhat human-readable form, that's:
+/// `1 / (list.len - 1) * sum_n (x[n] - mean(x))^2`.
+///
+/// In Typst, that's:
+///
+/// ```typst
+/// $"variance"(x) = 1 / ("count"(x) - 1) sum_(n = 1)^"count"(x) (x_n - "mean"(x))^2$
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() <= 1`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{stddev, variance};
+/// #
+/// assert_eq!(variance(&[2.0, 3.0, 4.0]), 1.0);
+/// assert_eq!(variance(&[10.0, 25.0, 50.0]), 408.33333333333337);
+/// assert_eq!(variance(&[10.0, 25.0, 50.0]).sqrt(), stddev(&[10.0, 25.0, 50.0]));
+/// assert!(variance(&[2.0]).is_nan());
+/// ```
+#[must_use]
+pub fn variance<F: Float>(list: &[F]) -> F {
+    // Avoid overflow (during `0 - 1`) and divide by zero (from `1 - 1`).
+    if list.len() <= 1 {
+        return F::new(1.0 / 0.0 * 0.0);
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    (sum_of_squared_deviations(list) / ((list.len() - 1) as f64)).into()
+}
+
+/// Computes the population variance of a list of values.
+///
+/// Unlike [`variance`], this divides by `list.len()` instead of `list.len() - 1`, so it is only
+/// appropriate when `list` is the entire population, not a sample of it.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::variance_population;
+/// #
+/// assert_eq!(variance_population(&[2.0, 3.0, 4.0]), 0.6666666666666666);
+/// assert_eq!(variance_population(&[10.0, 25.0, 50.0]), 272.22222222222223);
+/// assert_eq!(variance_population(&[2.0]), 0.0);
+/// assert!(variance_population::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn variance_population<F: Float>(list: &[F]) -> F {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    (sum_of_squared_deviations(list) / list.len() as f64).into()
+}
+
+/// Computes `sum_n (x[n] - mean(x))^2`, the shared numerator of [`variance`] and
+/// [`variance_population`].
+fn sum_of_squared_deviations<F: Float>(list: &[F]) -> f64 {
+    let mean = mean(list).get();
+
+    list.iter()
+        .map(|value| (value.get() - mean).powi(2))
+        .sum::<f64>()
+}
+
+/// Computes the corrected sample standard distribution of a list of values.
+///
+/// This is the square root of the [`variance`]. In a somewhat human-readable form, that's:
 /// `sqrt( 1 / (list.len - 1) * sum_n (x[n] - mean(x))^2 )`.
 ///
 /// In Typst, that's:
@@ -69,22 +400,1622 @@ pub fn mean<F: Float>(list: &[F]) -> F {
 /// ```
 #[must_use]
 pub fn stddev<F: Float>(list: &[F]) -> F {
-    // Avoid overflow (during `0 - 1`) and divide by zero (from `1 - 1`).
-    if list.len() <= 1 {
-        return F::new(1.0 / 0.0 * 0.0);
+    variance(list).get().sqrt().into()
+}
+
+/// Computes the geometric mean of a list of values: the `list.len()`th root of their product.
+///
+/// This is the appropriate mean for ratios and growth rates. It is calculated as the exponential
+/// of the [`mean`] of the natural logarithms of the values, `exp(mean(ln(x)))`, which avoids
+/// overflowing the product of many large values.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `list.len() == 0`.
+/// - Returns a [`f64::NAN`] if any value is negative, because their logarithms are undefined. If
+///   any value is zero (and none are negative), the result is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::geometric_mean;
+/// #
+/// assert_eq!(geometric_mean(&[1.0, 4.0]), 2.0);
+/// assert!((geometric_mean(&[2.0, 8.0, 4.0]) - 4.0).abs() < 0.000_000_000_1);
+///
+/// // The product of these would overflow.
+/// assert!((geometric_mean(&[1e300, 1e300, 1e300]) - 1e300).abs() < 1e290);
+///
+/// assert_eq!(geometric_mean(&[0.0, 4.0]), 0.0);
+/// assert!(geometric_mean(&[-1.0, 4.0]).is_nan());
+/// assert!(geometric_mean::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn geometric_mean<F: Float>(list: &[F]) -> F {
+    if list.iter().any(|value| value.get() < 0.0) {
+        return F::new(f64::NAN);
     }
 
-    let mean = mean(list).get();
+    let logarithms = list
+        .iter()
+        .map(|value| value.get().ln())
+        .collect::<Box<_>>();
+
+    mean(&logarithms).exp().into()
+}
+
+/// Computes the harmonic mean of a list of values: `list.len() / sum_n (1 / x[n])`.
+///
+/// This is the appropriate mean for rates, such as the average speed over several legs of equal
+/// distance.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `list.len() == 0`.
+/// - Returns a [`f64::NAN`] if any value is zero, because its reciprocal is undefined.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::harmonic_mean;
+/// #
+/// // Driving one leg at 40 km/h and another of the same length at 60 km/h averages 48 km/h.
+/// assert!((harmonic_mean(&[40.0, 60.0]) - 48.0).abs() < 0.000_000_000_1);
+///
+/// assert!(harmonic_mean(&[0.0, 4.0]).is_nan());
+/// assert!(harmonic_mean::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn harmonic_mean<F: Float>(list: &[F]) -> F {
+    if list.iter().any(|value| value.get() == 0.0) {
+        return F::new(f64::NAN);
+    }
 
     #[expect(
         clippy::cast_precision_loss,
         reason = "I can't think of another way to do this"
     )]
-    (1.0 / ((list.len() - 1) as f64)
-        * list
-            .iter()
-            .map(|value| (value.get() - mean).powi(2))
-            .sum::<f64>())
-    .sqrt()
-    .into()
+    (list.len() as f64)
+        .div(list.iter().map(|value| value.get().recip()).sum::<f64>())
+        .into()
+}
+
+/// Computes the root mean square of a list of values: `sqrt(mean(x^2))`.
+///
+/// This is the magnitude of a signal that varies around zero, such as the effective value of an
+/// alternating current.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::root_mean_square;
+/// #
+/// assert_eq!(root_mean_square(&[3.0, 4.0]), 12.5_f64.sqrt());
+///
+/// // One full period of a sine wave with an amplitude of 2.
+/// let amplitude = 2.0;
+/// let list = (0..100)
+///     .map(|n| f64::from(n) / 100.0 * std::f64::consts::TAU)
+///     .map(|t| amplitude * t.sin())
+///     .collect::<Vec<_>>();
+/// assert!((root_mean_square(&list) - amplitude / 2.0_f64.sqrt()).abs() < 0.000_000_1);
+///
+/// assert!(root_mean_square::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn root_mean_square<F: Float>(list: &[F]) -> F {
+    let squares = list
+        .iter()
+        .map(|value| value.get().powi(2))
+        .collect::<Box<_>>();
+
+    mean(&squares).sqrt().into()
+}
+
+/// Computes the geometric standard deviation of a list of values.
+///
+/// This is the multiplicative counterpart to [`stddev`], commonly used to describe the spread of
+/// log-normally distributed data. It is calculated as the exponential of the [`stddev`] of the
+/// natural logarithms of the values: `exp(stddev(ln(x)))`.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `list.len() <= 1`.
+/// - Returns a [`f64::NAN`] if any value is not positive, because their logarithms are undefined.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{geometric_stddev, stddev};
+/// #
+/// use std::f64::consts::E;
+///
+/// // `ln(x) = [0, 1, 2]`, which has a standard deviation of `1`.
+/// let list = [1.0, E, E * E];
+/// assert!((geometric_stddev(&list) - E).abs() < 0.000_000_000_1);
+///
+/// // Log-normally distributed data, the exponentials of normally distributed samples.
+/// let normal_samples = [0.71, 1.42, 0.88, 1.13, 0.27, 1.61, 0.95, 1.04];
+/// let list = normal_samples.map(f64::exp);
+/// assert!((geometric_stddev(&list) - stddev(&normal_samples).exp()).abs() < 0.000_000_000_1);
+/// assert!((geometric_stddev(&list) - 1.513_148_252_207_997).abs() < 0.000_000_000_1);
+///
+/// assert!(geometric_stddev(&[1.0, -1.0, 2.0]).is_nan());
+/// assert!(geometric_stddev(&[1.0, 0.0, 2.0]).is_nan());
+/// assert!(geometric_stddev(&[2.0]).is_nan());
+/// ```
+#[must_use]
+pub fn geometric_stddev<F: Float>(list: &[F]) -> F {
+    if list.iter().any(|value| value.get() <= 0.0) {
+        return F::new(f64::NAN);
+    }
+
+    let logarithms = list
+        .iter()
+        .map(|value| value.get().ln())
+        .collect::<Box<_>>();
+
+    stddev(&logarithms).exp().into()
+}
+
+/// Computes the skewness of a list of values, a measure of how asymmetric their distribution is.
+///
+/// This is the third central moment (with an `n` denominator) over the cube of the [`stddev`]:
+/// `mean((x - mean(x))^3) / stddev(x)^3`. It is positive when the distribution has a longer tail
+/// to the right, negative when it has a longer tail to the left, and zero when it is symmetric.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `list.len() < 3`.
+/// - Returns a [`f64::NAN`] if every value is the same (zero variance).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::skewness;
+/// #
+/// assert!(skewness(&[1.0, 2.0, 3.0, 4.0, 5.0]).abs() < 0.000_000_000_1);
+/// assert!(skewness(&[1.0, 1.0, 2.0, 2.0, 10.0]) > 0.0);
+/// assert!(skewness(&[-10.0, 1.0, 1.0, 2.0, 2.0]) < 0.0);
+///
+/// assert!(skewness(&[1.0, 2.0]).is_nan());
+/// assert!(skewness(&[2.0, 2.0, 2.0]).is_nan());
+/// ```
+#[must_use]
+pub fn skewness<F: Float>(list: &[F]) -> f64 {
+    if list.len() < 3 {
+        return f64::NAN;
+    }
+
+    standardized_moment(list, 3)
+}
+
+/// Computes the excess kurtosis of a list of values, a measure of how heavy the tails of their
+/// distribution are compared to a normal distribution.
+///
+/// This is the fourth central moment (with an `n` denominator) over the fourth power of the
+/// [`stddev`], minus three: `mean((x - mean(x))^4) / stddev(x)^4 - 3`. Subtracting three makes the
+/// kurtosis of a normal distribution zero. It is positive for heavier tails (more outliers) and
+/// negative for lighter tails.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `list.len() < 4`.
+/// - Returns a [`f64::NAN`] if every value is the same (zero variance).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::kurtosis;
+/// #
+/// // Uniformly distributed data has light tails.
+/// assert!(kurtosis(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]) < 0.0);
+/// // A single outlier makes for a heavy tail.
+/// assert!(kurtosis(&[5.0, 5.1, 4.9, 5.0, 5.2, 4.8, 5.0, 50.0]) > 0.0);
+///
+/// assert!(kurtosis(&[1.0, 2.0, 3.0]).is_nan());
+/// assert!(kurtosis(&[2.0, 2.0, 2.0, 2.0]).is_nan());
+/// ```
+#[must_use]
+pub fn kurtosis<F: Float>(list: &[F]) -> f64 {
+    if list.len() < 4 {
+        return f64::NAN;
+    }
+
+    standardized_moment(list, 4) - 3.0
+}
+
+/// Computes the `order`th central moment (with an `n` denominator) of a list of values over the
+/// `order`th power of their [`stddev`], or [`f64::NAN`] if their variance is zero.
+fn standardized_moment<F: Float>(list: &[F], order: i32) -> f64 {
+    let stddev = stddev(list).get();
+    if stddev == 0.0 {
+        return f64::NAN;
+    }
+
+    let center = mean(list).get();
+    let moments = list
+        .iter()
+        .map(|value| (value.get() - center).powi(order))
+        .collect::<Box<_>>();
+
+    mean(&moments) / stddev.powi(order)
+}
+
+/// Computes the pooled standard deviation of several groups of values.
+///
+/// This estimates the common standard deviation of groups that are assumed to share the same
+/// variance, weighting each group's variance by its degrees of freedom (`group.len() - 1`):
+/// `sqrt( sum_i (n_i - 1) * stddev(x_i)^2 / sum_i (n_i - 1) )`. It is the standard precursor to
+/// a two-sample t-test.
+///
+/// Groups with fewer than two values have no degrees of freedom, so they do not contribute.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if the total count of values is not greater than the number of groups
+/// (i.e., if there are no degrees of freedom).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::pooled_stddev;
+/// #
+/// // Variances of `1` and `20 / 3`, with `2` and `3` degrees of freedom, respectively.
+/// let a = [2.0, 3.0, 4.0];
+/// let b = [1.0, 3.0, 5.0, 7.0];
+/// // `sqrt( (2 * 1 + 3 * 20 / 3) / (2 + 3) ) = sqrt(4.4)`.
+/// assert!((pooled_stddev(&[&a, &b]) - 4.4_f64.sqrt()).abs() < 0.000_000_000_1);
+///
+/// // A single group is just its standard deviation.
+/// assert_eq!(pooled_stddev(&[&a]), 1.0);
+///
+/// assert!(pooled_stddev(&[&[1.0], &[2.0]]).is_nan());
+/// assert!(pooled_stddev::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn pooled_stddev<F: Float>(groups: &[&[F]]) -> F {
+    let degrees_of_freedom = groups
+        .iter()
+        .map(|group| group.len().saturating_sub(1))
+        .sum::<usize>();
+
+    if degrees_of_freedom == 0 {
+        return F::new(f64::NAN);
+    }
+
+    let sum_of_squares = groups
+        .iter()
+        .filter(|group| group.len() > 1)
+        .map(|group| {
+            let mean = mean(group).get();
+            group
+                .iter()
+                .map(|value| (value.get() - mean).powi(2))
+                .sum::<f64>()
+        })
+        .sum::<f64>();
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    (sum_of_squares / degrees_of_freedom as f64).sqrt().into()
+}
+
+/// Computes the sample covariance of two lists of values.
+///
+/// In a somewhat human-readable form, that's:
+/// `1 / (list.len - 1) * sum_n (x[n] - mean(x)) * (y[n] - mean(y))`.
+///
+/// `xs[n]` is paired with `ys[n]`. The covariance of a list with itself is its [`variance`].
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `xs.len() != ys.len()`.
+/// - Returns a [`f64::NAN`] if `xs.len() <= 1`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{covariance, variance};
+/// #
+/// // The means are 2 and 4, so this is `((-1 * -2) + (0 * -1) + (1 * 3)) / 2`.
+/// assert_eq!(covariance(&[1.0, 2.0, 3.0], &[2.0, 3.0, 7.0]), 2.5);
+/// assert_eq!(covariance(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]), variance(&[1.0, 2.0, 3.0]));
+///
+/// assert!(covariance(&[1.0, 2.0, 3.0], &[2.0, 4.0]).is_nan());
+/// assert!(covariance(&[1.0], &[2.0]).is_nan());
+/// ```
+#[must_use]
+pub fn covariance<F: Float>(xs: &[F], ys: &[F]) -> f64 {
+    if xs.len() != ys.len() || xs.len() <= 1 {
+        return f64::NAN;
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    let degrees_of_freedom = (xs.len() - 1) as f64;
+
+    sum_of_products_of_deviations(xs, ys) / degrees_of_freedom
+}
+
+/// Computes the population covariance of two lists of values.
+///
+/// Unlike [`covariance`], this divides by `xs.len()` instead of `xs.len() - 1`, so it is only
+/// appropriate when `xs` and `ys` are the entire population, not a sample of it.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `xs.len() != ys.len()`.
+/// - Returns a [`f64::NAN`] if `xs.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::covariance_population;
+/// #
+/// // The means are 2 and 4, so this is `((-1 * -2) + (0 * -1) + (1 * 3)) / 3`.
+/// assert_eq!(covariance_population(&[1.0, 2.0, 3.0], &[2.0, 3.0, 7.0]), 5.0 / 3.0);
+/// assert_eq!(covariance_population(&[1.0], &[2.0]), 0.0);
+///
+/// assert!(covariance_population(&[1.0, 2.0, 3.0], &[2.0, 4.0]).is_nan());
+/// assert!(covariance_population::<f64>(&[], &[]).is_nan());
+/// ```
+#[must_use]
+pub fn covariance_population<F: Float>(xs: &[F], ys: &[F]) -> f64 {
+    if xs.len() != ys.len() {
+        return f64::NAN;
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    let count = xs.len() as f64;
+
+    sum_of_products_of_deviations(xs, ys) / count
+}
+
+/// Computes `sum_n (x[n] - mean(x)) * (y[n] - mean(y))`, the shared numerator of [`covariance`]
+/// and [`covariance_population`].
+///
+/// Assumes that `xs.len() == ys.len()`.
+fn sum_of_products_of_deviations<F: Float>(xs: &[F], ys: &[F]) -> f64 {
+    let mean_x = mean(xs).get();
+    let mean_y = mean(ys).get();
+
+    xs.iter()
+        .zip(ys)
+        .map(|(x, y)| (x.get() - mean_x) * (y.get() - mean_y))
+        .sum::<f64>()
+}
+
+/// Computes the Pearson product-moment correlation coefficient between two lists of values.
+///
+/// This is a measure of how linearly related `xs` and `ys` are, from `1.0` (perfectly correlated)
+/// through `0.0` (uncorrelated) to `-1.0` (perfectly anti-correlated). `xs[n]` is paired with
+/// `ys[n]`. It is calculated as `covariance(x, y) / (stddev(x) * stddev(y))`.
+///
+/// # Errors
+///
+/// - Returns a [`f64::NAN`] if `xs.len() != ys.len()`.
+/// - Returns a [`f64::NAN`] if `xs.len() <= 1`.
+/// - Returns a [`f64::NAN`] if either list has zero variance (all of its values are the same).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::correlation;
+/// #
+/// assert_eq!(correlation(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]), 1.0);
+/// assert_eq!(correlation(&[1.0, 2.0, 3.0], &[6.0, 4.0, 2.0]), -1.0);
+/// assert_eq!(correlation(&[1.0, 2.0, 3.0], &[1.0, 5.0, 1.0]), 0.0);
+///
+/// assert!(correlation(&[1.0, 2.0, 3.0], &[2.0, 4.0]).is_nan());
+/// assert!(correlation(&[1.0, 2.0, 3.0], &[2.0, 2.0, 2.0]).is_nan());
+/// ```
+#[must_use]
+pub fn correlation<F: Float>(xs: &[F], ys: &[F]) -> f64 {
+    let spread = stddev(xs).get() * stddev(ys).get();
+
+    if spread == 0.0 {
+        return f64::NAN;
+    }
+
+    covariance(xs, ys) / spread
+}
+
+/// The result of a t-test, see [`t_test_two_sample`].
+#[cfg_attr(any(feature = "serde", test), derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default)]
+pub struct TTestResult {
+    /// The t-statistic.
+    t_statistic: f64,
+
+    /// The degrees of freedom of the t-distribution to compare the t-statistic against.
+    degrees_of_freedom: f64,
+}
+
+impl TTestResult {
+    /// Construct a new instance of [`Self`].
+    #[must_use]
+    pub const fn new(t_statistic: f64, degrees_of_freedom: f64) -> Self {
+        Self {
+            t_statistic,
+            degrees_of_freedom,
+        }
+    }
+
+    /// Returns the t-statistic.
+    #[must_use]
+    pub const fn t_statistic(&self) -> f64 {
+        self.t_statistic
+    }
+
+    /// Returns the degrees of freedom.
+    ///
+    /// This is a whole number for Student's t-test, but generally is not for Welch's t-test.
+    #[must_use]
+    pub const fn degrees_of_freedom(&self) -> f64 {
+        self.degrees_of_freedom
+    }
+}
+
+/// Performs a two-sample t-test, comparing the means of two independent groups of values.
+///
+/// If `equal_variance` is true, this is Student's t-test, which assumes that both groups share the
+/// same variance and estimates it with [`pooled_stddev`]. The degrees of freedom are
+/// `a.len() + b.len() - 2`.
+///
+/// Otherwise, this is Welch's t-test, which does not assume equal variances. The degrees of
+/// freedom are estimated by the Welch--Satterthwaite equation.
+///
+/// The t-statistic is positive when the mean of `a` is greater than the mean of `b`.
+///
+/// # Errors
+///
+/// Returns a [`TTestResult`] with [`f64::NAN`] for both values if either `a.len() <= 1` or
+/// `b.len() <= 1`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::t_test_two_sample;
+/// #
+/// let a = [19.7, 20.4, 18.9, 21.2, 20.0];
+/// let b = [22.1, 21.5, 23.0, 22.4, 21.8, 22.9];
+///
+/// // Student's t-test.
+/// let student = t_test_two_sample(&a, &b, true);
+/// assert!((student.t_statistic() - -5.137_371_317_686_182).abs() < 0.000_000_000_1);
+/// assert_eq!(student.degrees_of_freedom(), 9.0);
+///
+/// // Welch's t-test.
+/// let welch = t_test_two_sample(&a, &b, false);
+/// assert!((welch.t_statistic() - -4.964_255_496_554_606).abs() < 0.000_000_000_1);
+/// assert!((welch.degrees_of_freedom() - 7.022_948_771_722_814).abs() < 0.000_000_000_1);
+///
+/// let invalid = t_test_two_sample(&a, &[1.0], false);
+/// assert!(invalid.t_statistic().is_nan());
+/// assert!(invalid.degrees_of_freedom().is_nan());
+/// ```
+#[must_use]
+pub fn t_test_two_sample<F: Float>(a: &[F], b: &[F], equal_variance: bool) -> TTestResult {
+    if a.len() <= 1 || b.len() <= 1 {
+        return TTestResult::new(f64::NAN, f64::NAN);
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    let (a_len, b_len) = (a.len() as f64, b.len() as f64);
+    let difference = mean(a).get() - mean(b).get();
+
+    if equal_variance {
+        let pooled = pooled_stddev(&[a, b]).get();
+        let standard_error = pooled * (1.0 / a_len + 1.0 / b_len).sqrt();
+
+        return TTestResult::new(difference / standard_error, a_len + b_len - 2.0);
+    }
+
+    // The squared standard errors of each mean.
+    let a_error = stddev(a).get().powi(2) / a_len;
+    let b_error = stddev(b).get().powi(2) / b_len;
+    let squared_error = a_error + b_error;
+
+    let degrees_of_freedom =
+        squared_error.powi(2) / (a_error.powi(2) / (a_len - 1.0) + b_error.powi(2) / (b_len - 1.0));
+
+    TTestResult::new(difference / squared_error.sqrt(), degrees_of_freedom)
+}
+
+/// The result of fitting a line to a list of points, see [`linear_regression`] and
+/// [`weighted_linear_regression`].
+#[cfg_attr(any(feature = "serde", test), derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default)]
+pub struct LinearFit {
+    /// The slope of the line and its uncertainty.
+    slope: UncertainFloat<f64>,
+
+    /// The intercept of the line (its value at `t = 0`) and its uncertainty.
+    intercept: UncertainFloat<f64>,
+
+    /// The coefficient of determination, the fraction of the variance in the data that the line
+    /// accounts for.
+    r_squared: f64,
+}
+
+impl LinearFit {
+    /// Construct a new instance of [`Self`].
+    #[must_use]
+    pub const fn new(
+        slope: UncertainFloat<f64>,
+        intercept: UncertainFloat<f64>,
+        r_squared: f64,
+    ) -> Self {
+        Self {
+            slope,
+            intercept,
+            r_squared,
+        }
+    }
+
+    /// Returns the slope of the line and its uncertainty.
+    #[must_use]
+    pub const fn slope(&self) -> UncertainFloat<f64> {
+        self.slope
+    }
+
+    /// Returns the intercept of the line (its value at `t = 0`) and its uncertainty.
+    #[must_use]
+    pub const fn intercept(&self) -> UncertainFloat<f64> {
+        self.intercept
+    }
+
+    /// Returns the coefficient of determination (`R^2`).
+    ///
+    /// This is `1` if the line passes through every point, and approaches `0` as the line explains
+    /// less of the data.
+    #[must_use]
+    pub const fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// Evaluates the line at `t`, ignoring uncertainty.
+    #[must_use]
+    pub const fn predict(&self, t: f64) -> f64 {
+        self.slope.value().mul_add(t, *self.intercept.value())
+    }
+}
+
+/// Fits a line to a list of points with uncertain `F` values using weighted least squares.
+///
+/// Each point is weighted by `1 / σ^2`, where `σ` is its uncertainty, so that less reliable points
+/// have less influence on the fit. The uncertainties of the slope and intercept are propagated
+/// from the uncertainties of the points:
+///
+/// ```typst
+/// $Delta = (sum w) (sum w t^2) - (sum w t)^2$
+/// $σ_"slope" = sqrt(sum w / Delta)$
+/// $σ_"intercept" = sqrt(sum w t^2 / Delta)$
+/// ```
+///
+/// The [`LinearFit::r_squared`] is weighted in the same way.
+///
+/// # Errors
+///
+/// Returns a [`LinearFit`] with [`f64::NAN`] for every value if `list.len() < 2`, if every `T`
+/// value is the same, or if any uncertainty is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{statistics::weighted_linear_regression, units::UncertainFloat};
+/// #
+/// // `f = 2t + 1`, except for an outlier with a very large uncertainty.
+/// let points = [
+///     (0.0, 1.0, 0.1),
+///     (1.0, 3.0, 0.1),
+///     (2.0, 5.0, 0.1),
+///     (3.0, 7.0, 0.1),
+///     (4.0, 20.0, 100.0),
+/// ];
+/// let weighted = points.map(|(t, f, σ)| (t, UncertainFloat::new(f, σ)));
+/// // Treating every point as equally reliable, like ordinary least squares.
+/// let unweighted = points.map(|(t, f, _)| (t, UncertainFloat::new(f, 1.0)));
+///
+/// let fit = weighted_linear_regression(&weighted);
+/// assert!((fit.slope().value() - 2.0).abs() < 0.001);
+/// assert!((fit.intercept().value() - 1.0).abs() < 0.001);
+/// assert!((fit.predict(10.0) - 21.0).abs() < 0.01);
+///
+/// // The outlier drags the unweighted fit far away.
+/// let ordinary = weighted_linear_regression(&unweighted);
+/// assert!((ordinary.slope().value() - 2.0).abs() > 1.0);
+/// assert!(ordinary.r_squared() < fit.r_squared());
+///
+/// assert!(weighted_linear_regression(&weighted[..1]).slope().value().is_nan());
+/// ```
+#[must_use]
+pub fn weighted_linear_regression<T: Float>(list: &[(T, UncertainFloat<f64>)]) -> LinearFit {
+    let invalid = LinearFit::new(
+        UncertainFloat::new(f64::NAN, f64::NAN),
+        UncertainFloat::new(f64::NAN, f64::NAN),
+        f64::NAN,
+    );
+
+    if list.len() < 2 {
+        return invalid;
+    }
+
+    let (
+        mut weight_sum,
+        mut independent_sum,
+        mut dependent_sum,
+        mut independent_squared_sum,
+        mut product_sum,
+    ) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for (t, f) in list {
+        let (t, uncertainty, f) = (t.get(), *f.uncertainty(), *f.value());
+        let w = uncertainty.powi(-2);
+
+        weight_sum += w;
+        independent_sum += w * t;
+        dependent_sum += w * f;
+        independent_squared_sum = (w * t).mul_add(t, independent_squared_sum);
+        product_sum = (w * t).mul_add(f, product_sum);
+    }
+
+    let delta = weight_sum.mul_add(independent_squared_sum, -independent_sum.powi(2));
+    if !delta.is_normal() || !weight_sum.is_finite() {
+        return invalid;
+    }
+
+    let slope = weight_sum.mul_add(product_sum, -independent_sum * dependent_sum) / delta;
+    let intercept =
+        independent_squared_sum.mul_add(dependent_sum, -independent_sum * product_sum) / delta;
+
+    let mean_f = dependent_sum / weight_sum;
+    let (residual, total) = list.iter().fold((0.0, 0.0), |(residual, total), (t, f)| {
+        let w = f.uncertainty().powi(-2);
+        let f = *f.value();
+        let predicted = slope.mul_add(t.get(), intercept);
+
+        (
+            (w * (f - predicted)).mul_add(f - predicted, residual),
+            (w * (f - mean_f)).mul_add(f - mean_f, total),
+        )
+    });
+
+    LinearFit::new(
+        UncertainFloat::new(slope, (weight_sum / delta).sqrt()),
+        UncertainFloat::new(intercept, (independent_squared_sum / delta).sqrt()),
+        1.0 - residual / total,
+    )
+}
+
+/// Fits a line to a list of points by ordinary least squares.
+///
+/// This uses the closed-form least-squares formulas, where `S_tf = sum (t - mean(t)) (f - mean(f))`
+/// and so on:
+///
+/// ```typst
+/// $"slope" = S_(t f) / S_(t t)$
+/// $"intercept" = "mean"(f) - "slope" dot "mean"(t)$
+/// $R^2 = S_(t f)^2 / (S_(t t) S_(f f))$
+/// ```
+///
+/// The uncertainties of the slope and intercept are their standard errors, estimated from the
+/// scatter of the points about the line. Every point is treated as equally reliable, see
+/// [`weighted_linear_regression`] for points with known uncertainties.
+///
+/// # Errors
+///
+/// - Returns [`None`] if `list.len() < 2` or if every `T` value is the same (a vertical line).
+/// - The uncertainties are [`f64::NAN`] if `list.len() == 2`, because two points can't show any
+///   scatter about a line.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::linear_regression;
+/// #
+/// let fit = linear_regression(&[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]).unwrap();
+/// assert_eq!(*fit.slope().value(), 2.0);
+/// assert_eq!(*fit.intercept().value(), 1.0);
+/// assert_eq!(fit.r_squared(), 1.0);
+///
+/// // Collinear points have no uncertainty.
+/// assert_eq!(*fit.slope().uncertainty(), 0.0);
+///
+/// // Two points fit a line exactly, but with unknown uncertainty.
+/// let fit = linear_regression(&[(0.0, 1.0), (1.0, 3.0)]).unwrap();
+/// assert_eq!(*fit.slope().value(), 2.0);
+/// assert!(fit.slope().uncertainty().is_nan());
+///
+/// assert!(linear_regression(&[(0.0, 1.0)]).is_none());
+/// assert!(linear_regression(&[(1.0, 1.0), (1.0, 3.0)]).is_none());
+/// ```
+#[must_use]
+pub fn linear_regression<T: Float, F: Float>(list: &[(T, F)]) -> Option<LinearFit> {
+    let points = list
+        .iter()
+        .map(|(t, f)| (t.get(), f.get()))
+        .collect::<Box<_>>();
+
+    ordinary_least_squares(&points)
+}
+
+/// The result of fitting `f = a t^b` to a list of points, see [`power_law_fit`] and
+/// [`exponential_fit`].
+#[cfg_attr(any(feature = "serde", test), derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default)]
+pub struct PowerFit {
+    /// The coefficient `a`.
+    coefficient: f64,
+
+    /// The exponent `b`.
+    exponent: f64,
+
+    /// The coefficient of determination of the linearized fit.
+    r_squared: f64,
+}
+
+impl PowerFit {
+    /// Construct a new instance of [`Self`].
+    #[must_use]
+    pub const fn new(coefficient: f64, exponent: f64, r_squared: f64) -> Self {
+        Self {
+            coefficient,
+            exponent,
+            r_squared,
+        }
+    }
+
+    /// Returns the coefficient `a`.
+    #[must_use]
+    pub const fn coefficient(&self) -> f64 {
+        self.coefficient
+    }
+
+    /// Returns the exponent `b`.
+    ///
+    /// For [`exponential_fit`], this is the rate in `f = a e^(b t)`.
+    #[must_use]
+    pub const fn exponent(&self) -> f64 {
+        self.exponent
+    }
+
+    /// Returns the coefficient of determination (`R^2`) of the straight line fit to the
+    /// logarithms of the data, not of the curve fit to the data itself.
+    #[must_use]
+    pub const fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+}
+
+/// Fits a power law, `f = a t^b`, to a list of points.
+///
+/// This linearizes the data as `ln(f) = ln(a) + b ln(t)` and fits a line to it by ordinary least
+/// squares. Note that this minimizes the relative, rather than absolute, error of the curve.
+///
+/// Points where `t` or `f` are not positive can't be log-transformed, and are skipped.
+///
+/// # Errors
+///
+/// Returns a [`PowerFit`] with [`f64::NAN`] for every value if fewer than two points are positive
+/// or if every positive `T` value is the same.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::power_law_fit;
+/// #
+/// // `f = 3 t^1.5`, plus a point that can't be log-transformed.
+/// let list = [(0.0, 0.0), (1.0, 3.0), (2.0, 8.485_281), (4.0, 24.0), (9.0, 81.0)];
+///
+/// let fit = power_law_fit(&list);
+/// assert!((fit.exponent() - 1.5).abs() < 0.000_001);
+/// assert!((fit.coefficient() - 3.0).abs() < 0.000_001);
+/// assert!(fit.r_squared() > 0.999_999);
+/// ```
+#[must_use]
+pub fn power_law_fit<T: Float, F: Float>(list: &[(T, F)]) -> PowerFit {
+    let points = list
+        .iter()
+        .map(|(t, f)| (t.get(), f.get()))
+        .filter(|&(t, f)| t > 0.0 && f > 0.0)
+        .map(|(t, f)| (t.ln(), f.ln()))
+        .collect::<Box<_>>();
+
+    ordinary_least_squares(&points).map_or(PowerFit::new(f64::NAN, f64::NAN, f64::NAN), |fit| {
+        PowerFit::new(
+            fit.intercept().value().exp(),
+            *fit.slope().value(),
+            fit.r_squared(),
+        )
+    })
+}
+
+/// Fits an exponential curve, `f = a e^(b t)`, to a list of points.
+///
+/// This linearizes the data as `ln(f) = ln(a) + b t` and fits a line to it by ordinary least
+/// squares. Note that this minimizes the relative, rather than absolute, error of the curve.
+///
+/// Points where `f` is not positive can't be log-transformed, and are skipped.
+///
+/// # Errors
+///
+/// Returns a [`PowerFit`] with [`f64::NAN`] for every value if fewer than two points have positive
+/// `F` values or if all of their `T` values are the same.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::exponential_fit;
+/// #
+/// // Radioactive decay, `f = 100 e^(-0.5 t)`.
+/// let list = [0.0, 1.0, 2.0, 3.0, 4.0].map(|t: f64| (t, 100.0 * (-0.5 * t).exp()));
+///
+/// let fit = exponential_fit(&list);
+/// assert!((fit.exponent() - -0.5).abs() < 0.000_000_1);
+/// assert!((fit.coefficient() - 100.0).abs() < 0.000_001);
+/// ```
+#[must_use]
+pub fn exponential_fit<T: Float, F: Float>(list: &[(T, F)]) -> PowerFit {
+    let points = list
+        .iter()
+        .map(|(t, f)| (t.get(), f.get()))
+        .filter(|&(_, f)| f > 0.0)
+        .map(|(t, f)| (t, f.ln()))
+        .collect::<Box<_>>();
+
+    ordinary_least_squares(&points).map_or(PowerFit::new(f64::NAN, f64::NAN, f64::NAN), |fit| {
+        PowerFit::new(
+            fit.intercept().value().exp(),
+            *fit.slope().value(),
+            fit.r_squared(),
+        )
+    })
+}
+
+/// Fits a line to a list of points by ordinary least squares, see [`linear_regression`].
+///
+/// Returns [`None`] if `points.len() < 2` or if every `t` value is the same.
+fn ordinary_least_squares(points: &[(f64, f64)]) -> Option<LinearFit> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let mean_t = mean(&points.iter().map(|&(t, _)| t).collect::<Box<_>>());
+    let mean_f = mean(&points.iter().map(|&(_, f)| f).collect::<Box<_>>());
+
+    let (mut covariance, mut t_variance, mut f_variance) = (0.0, 0.0, 0.0);
+    for &(t, f) in points {
+        covariance = (t - mean_t).mul_add(f - mean_f, covariance);
+        t_variance = (t - mean_t).mul_add(t - mean_t, t_variance);
+        f_variance = (f - mean_f).mul_add(f - mean_f, f_variance);
+    }
+
+    if !t_variance.is_normal() {
+        return None;
+    }
+
+    let slope = covariance / t_variance;
+    let intercept = slope.mul_add(-mean_t, mean_f);
+    let r_squared = covariance.powi(2) / (t_variance * f_variance);
+
+    // The variance of the residuals, with two degrees of freedom used by the slope and intercept.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    let (count, residual_variance) = {
+        let count = points.len() as f64;
+        let residual_sum = slope.mul_add(-covariance, f_variance).max(0.0);
+
+        let residual_variance = if points.len() > 2 {
+            residual_sum / (count - 2.0)
+        } else {
+            f64::NAN
+        };
+
+        (count, residual_variance)
+    };
+
+    Some(LinearFit::new(
+        UncertainFloat::new(slope, (residual_variance / t_variance).sqrt()),
+        UncertainFloat::new(
+            intercept,
+            (residual_variance * (mean_t.powi(2) / t_variance + count.recip())).sqrt(),
+        ),
+        r_squared,
+    ))
+}
+
+/// Performs a Mann--Whitney U test, a non-parametric comparison of two independent groups of
+/// values that does not assume that either is normally distributed.
+///
+/// Returns the U statistic of `a`: the number of pairs `(a_i, b_j)` where `a_i > b_j`, with ties
+/// counting as one half. It is computed from ranks of the combined groups, where tied values share
+/// the average of the ranks they span. The U statistic of `b` is `a.len() * b.len() - U`, and most
+/// tables of critical values use the smaller of the two.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `a.len() == 0` or `b.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::mann_whitney_u;
+/// #
+/// // Only `4 > 3`.
+/// assert_eq!(mann_whitney_u(&[1.0, 2.0, 4.0], &[3.0, 5.0, 6.0]), 1.0);
+/// assert_eq!(mann_whitney_u(&[3.0, 5.0, 6.0], &[1.0, 2.0, 4.0]), 8.0);
+///
+/// // The tie between the threes counts as one half.
+/// assert_eq!(mann_whitney_u(&[1.0, 2.0, 3.0], &[3.0, 4.0]), 0.5);
+///
+/// assert!(mann_whitney_u(&[1.0], &[]).is_nan());
+/// ```
+#[must_use]
+pub fn mann_whitney_u<F: Float>(a: &[F], b: &[F]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return f64::NAN;
+    }
+
+    let combined: Box<[f64]> = a.iter().chain(b).map(Float::get).collect();
+    let rank_sum: f64 = ranks(&combined)[..a.len()].iter().sum();
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    let a_len = a.len() as f64;
+
+    rank_sum - a_len * (a_len + 1.0) / 2.0
+}
+
+/// Ranks each value in a list from `1` (smallest) to `list.len()` (largest), in the same order as
+/// the list. Tied values all get the average of the ranks they span.
+fn ranks(list: &[f64]) -> Box<[f64]> {
+    let mut order: Box<[usize]> = (0..list.len()).collect();
+    order.sort_by(|&lhs, &rhs| list[lhs].total_cmp(&list[rhs]));
+
+    let mut ranks = vec![0.0; list.len()].into_boxed_slice();
+    let mut start = 0;
+    for run in order.chunk_by(|&lhs, &rhs| list[lhs].total_cmp(&list[rhs]).is_eq()) {
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "I can't think of another way to do this"
+        )]
+        // The average of the ranks `start + 1..=start + run.len()`.
+        let rank = (2 * start + run.len() + 1) as f64 / 2.0;
+
+        for &index in run {
+            ranks[index] = rank;
+        }
+        start += run.len();
+    }
+
+    ranks
+}
+
+/// Extracts the dependent (`F`) values from a list of `(T, F)` pairs, as used by
+/// [`derivatives`].
+fn dependent_values<T: Float, F: Float>(list: &[(T, F)]) -> Box<[f64]> {
+    list.iter().map(|(_, f)| f.get()).collect()
+}
+
+/// Computes the [`mean`] of the dependent (`F`) values of a list of `(T, F)` pairs, such as those
+/// used by [`derivatives`].
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{mean, mean_of_dependent};
+/// #
+/// let list = [(0.0, 2.0), (1.0, 3.0), (2.0, 5.0)];
+/// assert_eq!(mean_of_dependent(&list), mean(&[2.0, 3.0, 5.0]));
+/// ```
+#[must_use]
+pub fn mean_of_dependent<T: Float, F: Float>(list: &[(T, F)]) -> F {
+    F::new(mean(&dependent_values(list)))
+}
+
+/// Computes the [`stddev`] of the dependent (`F`) values of a list of `(T, F)` pairs, such as
+/// those used by [`derivatives`].
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() <= 1`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{stddev, stddev_of_dependent};
+/// #
+/// let list = [(0.0, 10.0), (1.0, 25.0), (2.0, 50.0)];
+/// assert_eq!(stddev_of_dependent(&list), stddev(&[10.0, 25.0, 50.0]));
+/// ```
+#[must_use]
+pub fn stddev_of_dependent<T: Float, F: Float>(list: &[(T, F)]) -> F {
+    F::new(stddev(&dependent_values(list)))
+}
+
+/// Computes the [`geometric_stddev`] of the dependent (`F`) values of a list of `(T, F)` pairs,
+/// such as those used by [`derivatives`].
+///
+/// # Errors
+///
+/// The same as [`geometric_stddev`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{geometric_stddev, geometric_stddev_of_dependent};
+/// #
+/// let list = [(0.0, 1.0), (1.0, 2.0), (2.0, 8.0)];
+/// assert_eq!(geometric_stddev_of_dependent(&list), geometric_stddev(&[1.0, 2.0, 8.0]));
+/// ```
+#[must_use]
+pub fn geometric_stddev_of_dependent<T: Float, F: Float>(list: &[(T, F)]) -> F {
+    F::new(geometric_stddev(&dependent_values(list)))
+}
+
+/// Estimates a bandwidth for [`kde`] using Silverman's rule of thumb:
+/// `1.06 * stddev(x) * count(x)^(-1/5)`.
+///
+/// This is optimal for normally distributed data, and tends to oversmooth data with multiple
+/// peaks.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() <= 1`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::silverman_bandwidth;
+/// #
+/// let list = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// assert!((silverman_bandwidth(&list) - 1.214_735_905_665_934).abs() < 0.000_000_000_1);
+/// assert!(silverman_bandwidth(&[1.0]).is_nan());
+/// ```
+#[must_use]
+pub fn silverman_bandwidth<F: Float>(list: &[F]) -> f64 {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    let count = list.len() as f64;
+
+    1.06 * stddev(list).get() * count.powf(-0.2)
+}
+
+/// Computes a Gaussian kernel density estimate of the distribution of `list` at each of
+/// `eval_points`.
+///
+/// This is a smooth estimate of the probability density function that `list` was sampled from,
+/// made by placing a normal distribution with a standard deviation of `bandwidth` on every value
+/// and averaging them:
+///
+/// ```typst
+/// $"kde"(x) = 1 / ("count"(list) dot h) sum_(n = 1)^"count"(list)
+///     1 / sqrt(2 pi) e^(-1/2 ((x - list_n) / h)^2)$
+/// ```
+///
+/// A larger `bandwidth` produces a smoother estimate. If `bandwidth` is zero, negative, or NaN, it
+/// is automatically selected using Silverman's rule of thumb (see [`silverman_bandwidth`]).
+///
+/// # Errors
+///
+/// - Returns [`f64::NAN`] at every point if `list.len() == 0`.
+/// - Returns [`f64::NAN`] at every point if `list.len() == 1` and `bandwidth` needs to be
+///   automatically selected.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::kde;
+/// #
+/// let list = [1.0, 1.5, 2.0, 2.2, 3.1, 4.0, 4.2, 6.0];
+///
+/// // Evaluate the estimate on a fine grid, well past either end of the data.
+/// let step = 0.01;
+/// let grid = (-1_000..=2_000).map(|i| f64::from(i) * step).collect::<Box<_>>();
+///
+/// // Using a given bandwidth or an automatically selected one, the estimate is a probability
+/// // density, so it integrates to 1.
+/// for bandwidth in [0.5, 0.0] {
+///     let density = kde(&list, bandwidth, &grid);
+///     assert_eq!(density.len(), grid.len());
+///     assert!(density.iter().all(|&d| d >= 0.0));
+///
+///     let area = density.iter().sum::<f64>() * step;
+///     assert!((area - 1.0).abs() < 0.001, "{area}");
+/// }
+///
+/// assert!(kde::<f64>(&[], 1.0, &[0.0])[0].is_nan());
+/// ```
+#[must_use]
+pub fn kde<F: Float>(list: &[F], bandwidth: f64, eval_points: &[f64]) -> Box<[f64]> {
+    let bandwidth = if bandwidth > 0.0 {
+        bandwidth
+    } else {
+        silverman_bandwidth(list)
+    };
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "I can't think of another way to do this"
+    )]
+    let normalization = 1.0 / (list.len() as f64 * bandwidth * std::f64::consts::TAU.sqrt());
+
+    eval_points
+        .iter()
+        .map(|&point| {
+            list.iter()
+                .map(|value| {
+                    let z = (point - value.get()) / bandwidth;
+                    (-0.5 * z * z).exp()
+                })
+                .sum::<f64>()
+                * normalization
+        })
+        .collect()
+}
+
+/// Returns a sorted copy of the values of `list`, ordered by [`f64::total_cmp`] so that NaN values
+/// don't cause a panic.
+fn sorted_values<F: Float>(list: &[F]) -> Box<[f64]> {
+    let mut sorted = list.iter().map(Float::get).collect::<Box<_>>();
+    sorted.sort_unstable_by(f64::total_cmp);
+    sorted
+}
+
+/// Returns the `fraction` quantile (e.g., `0.25` for the first quartile) of an already sorted list
+/// of values, linearly interpolating between the closest ranks.
+///
+/// Returns [`f64::NAN`] if `sorted` is empty.
+fn quantile_of_sorted(sorted: &[f64], fraction: f64) -> f64 {
+    let Some(last_index) = sorted.len().checked_sub(1) else {
+        return f64::NAN;
+    };
+
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "the rank is clamped to be a valid, non-negative index"
+    )]
+    {
+        let rank = fraction.clamp(0.0, 1.0) * last_index as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+
+        (sorted[upper] - sorted[lower]).mul_add(rank - lower as f64, sorted[lower])
+    }
+}
+
+/// Computes the interquartile mean of a list of values: the [`mean`] of only the values between
+/// the first and third quartiles (inclusive), the middle half of the data.
+///
+/// This is a robust measure of central tendency. Like the median, it ignores outliers, but like the
+/// mean, it still uses many of the values. The quartiles are calculated by linearly interpolating
+/// between the closest ranks of a sorted copy of `list`.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() < 4`, because there aren't enough values to make up
+/// quartiles.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{interquartile_mean, mean};
+/// #
+/// // Most of the data is around 10, with a couple of large outliers.
+/// let list = [9.8, 10.1, 9.9, 10.0, 10.2, 9.7, 10.3, 10.0, 55.0, 80.0];
+///
+/// let robust = interquartile_mean(&list);
+/// assert!((robust - 10.075).abs() < 0.000_000_000_1);
+///
+/// // The plain mean is dragged away from the bulk of the data.
+/// assert!((robust - 10.0).abs() < (mean(&list) - 10.0).abs());
+/// assert!(mean(&list) > 20.0);
+///
+/// assert!(interquartile_mean::<f64>(&[1.0, 2.0, 3.0]).is_nan());
+/// ```
+#[must_use]
+pub fn interquartile_mean<F: Float>(list: &[F]) -> F {
+    if list.len() < 4 {
+        return F::new(f64::NAN);
+    }
+
+    let sorted = sorted_values(list);
+    let first_quartile = quantile_of_sorted(&sorted, 0.25);
+    let third_quartile = quantile_of_sorted(&sorted, 0.75);
+
+    let middle = sorted
+        .iter()
+        .copied()
+        .filter(|value| (first_quartile..=third_quartile).contains(value))
+        .collect::<Box<_>>();
+
+    F::new(mean(&middle))
+}
+
+/// Computes the `p`th percentile of a list of values, e.g., `p = 50.0` for the [`median`].
+///
+/// This linearly interpolates between the closest ranks of a sorted copy of `list`, ordered by
+/// [`f64::total_cmp`] so that NaN values don't cause a panic. `p` is clamped to `[0.0, 100.0]`, so
+/// out of range percentiles return the smallest or largest value.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::percentile;
+/// #
+/// let list = [4.0, 1.0, 3.0, 2.0];
+///
+/// assert_eq!(percentile(&list, 50.0), 2.5);
+/// assert_eq!(percentile(&list, 0.0), 1.0);
+/// assert_eq!(percentile(&list, 100.0), 4.0);
+/// assert_eq!(percentile(&list, 25.0), 1.75);
+///
+/// // Out of range percentiles are clamped.
+/// assert_eq!(percentile(&list, 150.0), 4.0);
+///
+/// assert!(percentile::<f64>(&[], 50.0).is_nan());
+/// ```
+#[must_use]
+pub fn percentile<F: Float>(list: &[F], p: f64) -> F {
+    F::new(quantile_of_sorted(&sorted_values(list), p / 100.0))
+}
+
+/// Computes the first, second, and third quartiles of a list of values: the 25th, 50th, and 75th
+/// [`percentile`]s.
+///
+/// The second quartile is the [`median`]. This only sorts `list` once, so it is cheaper than
+/// calling [`percentile`] three times.
+///
+/// # Errors
+///
+/// Returns [`f64::NAN`] for each quartile if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::quartiles;
+/// #
+/// assert_eq!(quartiles(&[1.0, 2.0, 3.0, 4.0, 5.0]), (2.0, 3.0, 4.0));
+/// assert_eq!(quartiles(&[4.0, 1.0, 3.0, 2.0]), (1.75, 2.5, 3.25));
+///
+/// let (first, second, third) = quartiles::<f64>(&[]);
+/// assert!(first.is_nan() && second.is_nan() && third.is_nan());
+/// ```
+#[must_use]
+pub fn quartiles<F: Float>(list: &[F]) -> (F, F, F) {
+    let sorted = sorted_values(list);
+
+    (
+        F::new(quantile_of_sorted(&sorted, 0.25)),
+        F::new(quantile_of_sorted(&sorted, 0.5)),
+        F::new(quantile_of_sorted(&sorted, 0.75)),
+    )
+}
+
+/// Computes the interquartile range of a list of values: the third quartile minus the first (see
+/// [`quartiles`]).
+///
+/// Like the [`stddev`], this is a measure of how spread out the data is, but it ignores outliers.
+///
+/// # Errors
+///
+/// Returns a [`f64::NAN`] if `list.len() == 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::iqr;
+/// #
+/// assert_eq!(iqr(&[1.0, 2.0, 3.0, 4.0, 5.0]), 2.0);
+/// assert_eq!(iqr(&[1.0, 2.0, 3.0, 4.0, 500.0]), 2.0);
+/// assert!(iqr::<f64>(&[]).is_nan());
+/// ```
+#[must_use]
+pub fn iqr<F: Float>(list: &[F]) -> F {
+    let (first_quartile, _, third_quartile) = quartiles(list);
+
+    F::new(third_quartile.get() - first_quartile.get())
+}
+
+/// Finds the indices of the outliers in a list of values, using Tukey's fences: values below
+/// `Q1 - k * IQR` or above `Q3 + k * IQR` (see [`quartiles`] and [`iqr`]).
+///
+/// `k` is typically `1.5` for outliers, or `3.0` for only the most extreme outliers. The indices
+/// are in ascending order, so they can be used to remove the outliers from (or otherwise clean up)
+/// `list` before further analysis, such as differentiation. NaN values are always outliers.
+///
+/// # Errors
+///
+/// Returns an empty list if `list.len() < 4`, because there aren't enough values to make up
+/// quartiles.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::outliers_iqr;
+/// #
+/// assert_eq!(outliers_iqr(&[1.0, 2.0, 3.0, 100.0], 1.5), [3]);
+/// assert_eq!(outliers_iqr(&[-50.0, 9.9, 10.0, 10.1, 10.0, 9.8], 1.5), [0]);
+/// assert!(outliers_iqr(&[1.0, 2.0, 3.0, 4.0], 1.5).is_empty());
+///
+/// // Too short to have quartiles.
+/// assert!(outliers_iqr(&[1.0, 2.0, 100.0], 1.5).is_empty());
+/// ```
+#[must_use]
+pub fn outliers_iqr<F: Float>(list: &[F], k: f64) -> Vec<usize> {
+    if list.len() < 4 {
+        return Vec::new();
+    }
+
+    let (first_quartile, _, third_quartile) = quartiles(list);
+    let (first_quartile, third_quartile) = (first_quartile.get(), third_quartile.get());
+    let range = third_quartile - first_quartile;
+
+    let fences = k.mul_add(-range, first_quartile)..=k.mul_add(range, third_quartile);
+
+    list.iter()
+        .enumerate()
+        .filter(|(_, value)| !fences.contains(&value.get()))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Accumulates statistics over a stream of values, without holding onto the values themselves.
+///
+/// This uses Welford's online algorithm, which updates the mean and the sum of squared deviations
+/// from it as each value is pushed. This is numerically stable, unlike accumulating the sum of
+/// squares directly. Every statistic returns [`f64::NAN`] where the corresponding batch function
+/// (like [`mean`] or [`stddev`]) would.
+///
+/// NaN values are handled like the batch functions handle them too: pushing a NaN makes the mean,
+/// variance, and standard deviation NaN from then on, while [`Self::min`] and [`Self::max`]
+/// compare with [`f64::total_cmp`] like [`min`] and [`max`] do.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::statistics::{RunningStats, mean, stddev, variance};
+/// #
+/// let list = [10.0, 25.0, 50.0, 12.5, -3.0];
+///
+/// let mut stats = RunningStats::new();
+/// for value in list {
+///     stats.push(value);
+/// }
+///
+/// assert_eq!(stats.count(), 5);
+/// assert!((stats.mean() - mean(&list)).abs() < 1e-12);
+/// assert!((stats.variance() - variance(&list)).abs() < 1e-12);
+/// assert!((stats.stddev() - stddev(&list)).abs() < 1e-12);
+/// assert_eq!(stats.min(), -3.0);
+/// assert_eq!(stats.max(), 50.0);
+///
+/// // Or collect it from an iterator.
+/// let collected = list.into_iter().collect::<RunningStats>();
+/// assert_eq!(collected, stats);
+/// ```
+///
+/// Like the batch functions, there must be enough values:
+///
+/// ```rust
+/// # use sciutil::statistics::RunningStats;
+/// #
+/// let mut stats = RunningStats::new();
+/// assert!(stats.mean().is_nan());
+/// assert!(stats.min().is_nan());
+///
+/// stats.extend([2.0]);
+/// assert_eq!(stats.mean(), 2.0);
+/// assert!(stats.variance().is_nan());
+/// assert!(stats.stddev().is_nan());
+///
+/// // Without any values, `min` and `max` are NaN, but that doesn't make empty statistics unequal.
+/// assert_eq!(RunningStats::new(), RunningStats::default());
+/// ```
+///
+/// NaN values agree with the batch functions:
+///
+/// ```rust
+/// # use sciutil::statistics::{RunningStats, max, mean, min};
+/// #
+/// let list = [3.0, f64::NAN, 2.0];
+/// let stats = list.into_iter().collect::<RunningStats>();
+///
+/// assert!(stats.mean().is_nan() && mean(&list).is_nan());
+/// assert_eq!(stats.min(), min(&list).unwrap());
+/// assert!(stats.max().is_nan() && max(&list).unwrap().is_nan());
+/// ```
+#[cfg_attr(any(feature = "serde", test), derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, Debug)]
+pub struct RunningStats {
+    /// The number of values pushed.
+    count: usize,
+
+    /// The mean of the values pushed, or zero if there are none.
+    mean: f64,
+
+    /// The sum of squared deviations from the mean of the values pushed.
+    sum_of_squares: f64,
+
+    /// The smallest value pushed, or NaN if there are none.
+    min: f64,
+
+    /// The largest value pushed, or NaN if there are none.
+    max: f64,
+}
+
+impl RunningStats {
+    /// Construct a new instance of [`Self`], without any values.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            sum_of_squares: 0.0,
+            min: f64::NAN,
+            max: f64::NAN,
+        }
+    }
+
+    /// Adds a value to the statistics.
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "I can't think of another way to do this"
+        )]
+        let count = self.count as f64;
+
+        let delta = value - self.mean;
+        self.mean += delta / count;
+        self.sum_of_squares = delta.mul_add(value - self.mean, self.sum_of_squares);
+
+        // Compare like `statistics::min` and `statistics::max`, so NaN is ordered rather than
+        // ignored. The first value replaces the initial NaN.
+        if self.count == 1 || value.total_cmp(&self.min).is_lt() {
+            self.min = value;
+        }
+        if self.count == 1 || value.total_cmp(&self.max).is_gt() {
+            self.max = value;
+        }
+    }
+
+    /// Returns the number of values pushed.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the values pushed, see [`mean`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`f64::NAN`] if [`Self::count`] is zero.
+    #[must_use]
+    pub const fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+
+        self.mean
+    }
+
+    /// Returns the corrected sample variance of the values pushed, see [`variance`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`f64::NAN`] if [`Self::count`] is at most one.
+    #[must_use]
+    pub fn variance(&self) -> f64 {
+        if self.count <= 1 {
+            return f64::NAN;
+        }
+
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "I can't think of another way to do this"
+        )]
+        let degrees_of_freedom = (self.count - 1) as f64;
+
+        self.sum_of_squares / degrees_of_freedom
+    }
+
+    /// Returns the corrected sample standard deviation of the values pushed, see [`stddev`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`f64::NAN`] if [`Self::count`] is at most one.
+    #[must_use]
+    pub fn stddev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Returns the smallest value pushed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`f64::NAN`] if [`Self::count`] is zero.
+    #[must_use]
+    pub const fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the largest value pushed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`f64::NAN`] if [`Self::count`] is zero.
+    #[must_use]
+    pub const fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Default for RunningStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for RunningStats {
+    fn eq(&self, other: &Self) -> bool {
+        // Without any values, `min` and `max` are NaN, which would never compare equal.
+        if self.count == 0 || other.count == 0 {
+            return self.count == other.count;
+        }
+
+        self.count == other.count
+            && self.mean.to_bits() == other.mean.to_bits()
+            && self.sum_of_squares.to_bits() == other.sum_of_squares.to_bits()
+            && self.min.to_bits() == other.min.to_bits()
+            && self.max.to_bits() == other.max.to_bits()
+    }
+}
+
+impl Extend<f64> for RunningStats {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl FromIterator<f64> for RunningStats {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
 }
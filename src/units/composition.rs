@@ -1,14 +1,20 @@
-use super::{Float, Unit};
+use super::{BaseUnit, Float, FloatDisplay, SymbolStyle, Unit};
+use crate::{err::IncompatibleUnitsError, rounding::digits::DigitsValue};
+
+use std::{fmt::Display, num::NonZeroUsize};
 
 // Dummy struct until proper integration
+#[cfg(test)]
 struct Seconds;
 
+#[cfg(test)]
 impl From<crate::units::Seconds> for Seconds {
     fn from(_: crate::units::Seconds) -> Self {
         Self
     }
 }
 
+#[cfg(test)]
 impl Unit for Seconds {
     fn symbol(&self) -> String {
         "s".to_string()
@@ -63,6 +69,56 @@ impl Multiplied for UnitListNull {
     }
 }
 
+mod sealed {
+    use super::Unit;
+
+    /// A [`super::Multiplied`] chain that can be walked without allocating.
+    ///
+    /// This is sealed, so that it can only be implemented by [`super::UnitList`] and
+    /// [`super::UnitListNull`], leaving the contract of [`super::Multiplied`] to implementors
+    /// outside of sciutil unchanged.
+    pub trait Chain {
+        /// Split [`Self`] into its first [`Unit`] and the rest of the chain, returning [`None`] if
+        /// [`Self`] contains no [`Unit`]s.
+        fn split_first(&self) -> Option<(&dyn Unit, &dyn Chain)>;
+    }
+}
+
+use sealed::Chain;
+
+impl Chain for UnitListNull {
+    fn split_first(&self) -> Option<(&dyn Unit, &dyn Chain)> {
+        None
+    }
+}
+
+/// An iterator over the [`Unit`]s in a [`UnitList`] that does not allocate.
+///
+/// Created by [`UnitList::iter`].
+#[derive(Clone)]
+pub struct MultipliedIter<'a>(Option<&'a dyn Chain>);
+
+impl<'a> MultipliedIter<'a> {
+    /// Create a new instance of [`Self`], iterating over `chain`.
+    const fn new(chain: &'a dyn Chain) -> Self {
+        Self(Some(chain))
+    }
+}
+
+impl<'a> Iterator for MultipliedIter<'a> {
+    type Item = &'a dyn Unit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some((unit, rest)) = self.0?.split_first() else {
+            self.0 = None;
+            return None;
+        };
+
+        self.0 = Some(rest);
+        Some(unit)
+    }
+}
+
 /// An implementation of [`Multiplied`].
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
 pub struct UnitList<T: Unit, V: Multiplied>(T, V);
@@ -75,10 +131,67 @@ impl<T: Unit, V: Multiplied> Multiplied for UnitList<T, V> {
     }
 }
 
+impl<T: Unit, V: Multiplied + Chain> Chain for UnitList<T, V> {
+    fn split_first(&self) -> Option<(&dyn Unit, &dyn Chain)> {
+        Some((&self.0, &self.1))
+    }
+}
+
 impl<T: Unit, V: Multiplied> Unit for UnitList<T, V> {
     fn symbol(&self) -> String {
         self.flatten_symbols()
     }
+
+    fn base_symbol(&self) -> String {
+        self.flatten_units()
+            .into_iter()
+            .map(Unit::base_symbol)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn to_base(&self) -> f64 {
+        self.flatten_units()
+            .into_iter()
+            .map(Unit::to_base)
+            .product()
+    }
+
+    fn symbol_styled(&self, style: SymbolStyle) -> String {
+        let units = self.flatten_units();
+
+        if style != SymbolStyle::Fraction {
+            return units
+                .into_iter()
+                .map(|unit| unit.symbol_styled(style))
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+
+        let mut numerator = Vec::new();
+        let mut denominator = Vec::new();
+        for unit in units {
+            let (symbol, power) = unit.symbol_parts();
+
+            if power.is_negative() {
+                denominator.push(SymbolStyle::Caret.apply(&symbol, -power));
+            } else {
+                numerator.push(SymbolStyle::Caret.apply(&symbol, power));
+            }
+        }
+
+        let numerator = if numerator.is_empty() {
+            "1".to_string()
+        } else {
+            numerator.join(" ")
+        };
+
+        match denominator.len() {
+            0 => numerator,
+            1 => format!("{numerator}/{}", denominator[0]),
+            _ => format!("{numerator}/({})", denominator.join(" ")),
+        }
+    }
 }
 
 impl<T: Unit, V: Multiplied> UnitList<T, V> {
@@ -95,11 +208,68 @@ impl<T: Unit, V: Multiplied> UnitList<T, V> {
     }
 }
 
+impl<T: Unit, V: Multiplied + Chain> UnitList<T, V> {
+    /// Iterate over the [`Unit`]s in [`Self`], first to last, without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{
+    /// #     Meters, Seconds,
+    /// #     composition::{UnitList, UnitListNull},
+    /// # };
+    /// #
+    /// let list = UnitList::new(Seconds, UnitListNull).prepend(Meters);
+    /// let symbols = list.iter().map(|unit| unit.symbol()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(symbols, ["m", "s"]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> MultipliedIter<'_> {
+        MultipliedIter::new(self)
+    }
+
+    /// Returns the number of [`Unit`]s in [`Self`], without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{
+    /// #     Meters, Seconds,
+    /// #     composition::{UnitList, UnitListNull},
+    /// # };
+    /// #
+    /// let list = UnitList::new(Seconds, UnitListNull).prepend(Meters).prepend(Meters);
+    ///
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    #[expect(
+        clippy::len_without_is_empty,
+        reason = "a `UnitList` always contains at least one unit"
+    )]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+}
+
+impl<'a, T: Unit, V: Multiplied + Chain> IntoIterator for &'a UnitList<T, V> {
+    type Item = &'a dyn Unit;
+    type IntoIter = MultipliedIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// Gives an `T` physical units.
 ///
 /// If the given types for the units are zero-sized, then this is a zero-cost wrapper. All sciutil
 /// unit types are zero-sized, so a [`Self`] of all sciutil types will be zero-cost.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+///
+/// [`Valued<f64, U>`] implements [`PartialOrd`] by comparing only the numeric values, so lists of
+/// measurements in the same unit can be sorted as expected.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
 pub struct Valued<T, U: Unit> {
     value: T,
     unit: U,
@@ -176,10 +346,223 @@ FloatImpl! {
     }
 }
 
+impl<U: Unit> Valued<f64, U> {
+    /// Converts the value of [`Self`] to a `target` unit chosen at runtime.
+    ///
+    /// Where the target unit is known at compile time, prefer the [`From`] implementations between
+    /// [`Valued`] types.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncompatibleUnitsError`] if `target` is not [compatible][`Unit::is_compatible`]
+    /// with the unit of [`Self`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{Centimeters, Float, Meters, Seconds, Unit, composition::Valued};
+    /// #
+    /// let length = Valued::<f64, Meters>::new(1.5);
+    ///
+    /// let target: &dyn Unit = &Centimeters;
+    /// assert!((length.try_convert(target).unwrap() - 150.0).abs() < 0.000_000_000_1);
+    ///
+    /// assert!(length.try_convert(&Seconds).is_err());
+    /// ```
+    pub fn try_convert(&self, target: &dyn Unit) -> Result<f64, IncompatibleUnitsError> {
+        if !self.unit.is_compatible(target) {
+            return Err(IncompatibleUnitsError);
+        }
+
+        Ok(self.value * self.unit.to_base() / target.to_base())
+    }
+
+    /// Converts [`Self`] to another unit `V` with the same [base unit][`BaseUnit`], routing the
+    /// conversion through that base unit.
+    ///
+    /// Unlike the [`From`] implementations between [`Valued`] types, this works for any pair of
+    /// units with the same base unit, so it can be used generically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{BaseUnit, Centimeters, Float, Micrometers, composition::Valued};
+    /// #
+    /// fn to_micrometers<U: BaseUnit<Base = <Micrometers as BaseUnit>::Base>>(
+    ///     value: Valued<f64, U>,
+    /// ) -> f64 {
+    ///     value.convert::<Micrometers>().get()
+    /// }
+    ///
+    /// let length = Valued::<f64, Centimeters>::new(2.5);
+    /// assert!((to_micrometers(length) - 25_000.0).abs() < 0.000_000_1);
+    /// ```
+    #[must_use]
+    pub fn convert<V: BaseUnit<Base = U::Base> + Default>(self) -> Valued<f64, V>
+    where
+        U: BaseUnit,
+    {
+        let unit = V::default();
+
+        Valued::from_unit(self.value * self.unit.to_base() / unit.to_base(), unit)
+    }
+
+    /// Returns the absolute value of [`Self`], keeping its unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{Float, Meters, composition::Valued};
+    /// #
+    /// let displacement = Valued::<f64, Meters>::new(-2.5);
+    /// assert_eq!(displacement.abs(), Valued::new(2.5));
+    /// ```
+    #[must_use]
+    pub fn abs(self) -> Self {
+        Self {
+            value: self.value.abs(),
+            unit: self.unit,
+        }
+    }
+}
+
+/// Returns whichever of `a` and `b` has the smaller value, keeping its unit.
+///
+/// Like [`f64::min`], if one of the values is NaN, the other is returned. If the values are equal,
+/// `a` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::units::{Float, Meters, composition::{self, Valued}};
+/// #
+/// let a = Valued::<f64, Meters>::new(1.0);
+/// let b = Valued::<f64, Meters>::new(f64::NAN);
+/// assert_eq!(composition::min(a, Valued::new(2.0)), a);
+/// assert_eq!(composition::min(a, b), a);
+/// ```
+#[must_use]
+pub fn min<U: Unit>(a: Valued<f64, U>, b: Valued<f64, U>) -> Valued<f64, U> {
+    if b.value < a.value || a.value.is_nan() {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns whichever of `a` and `b` has the larger value, keeping its unit.
+///
+/// Like [`f64::max`], if one of the values is NaN, the other is returned. If the values are equal,
+/// `a` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::units::{Float, Meters, composition::{self, Valued}};
+/// #
+/// let a = Valued::<f64, Meters>::new(1.0);
+/// let b = Valued::<f64, Meters>::new(f64::NAN);
+/// assert_eq!(composition::max(a, Valued::new(2.0)), Valued::new(2.0));
+/// assert_eq!(composition::max(a, b), a);
+/// ```
+#[must_use]
+pub fn max<U: Unit>(a: Valued<f64, U>, b: Valued<f64, U>) -> Valued<f64, U> {
+    if b.value > a.value || a.value.is_nan() {
+        b
+    } else {
+        a
+    }
+}
+
+impl<U: Unit + PartialEq> PartialOrd for Valued<f64, U> {
+    /// Compares the numeric values of `self` and `other`.
+    ///
+    /// Returns [`None`] if either value is NaN, or if the unit values differ (which is never the
+    /// case for the zero-sized sciutil unit types).
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.unit != other.unit {
+            return None;
+        }
+
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<U: Unit> Display for Valued<f64, U> {
+    /// Formats the value followed by the symbol of its unit.
+    ///
+    /// The precision flag sets the number of significant figures to round the value to, using the
+    /// same rounding as the rest of sciutil (see [`crate::rounding::digits::Digits`], which rounds
+    /// ties to even). Without a precision flag, or with a precision of zero, the value is printed
+    /// in full.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{Float, Meters, composition::Valued};
+    /// #
+    /// let length = Valued::<f64, Meters>::new(1_024.05);
+    ///
+    /// assert_eq!(length.to_string(), "1024.05 m");
+    /// assert_eq!(format!("{length:.2}"), "1000 m");
+    /// assert_eq!(format!("{length:.5}"), "1024.0 m");
+    /// assert_eq!(format!("{:.3}", Valued::<f64, Meters>::new(0.012_345)), "0.0123 m");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = DigitsValue::new(&self.value);
+
+        match (value.finite(), f.precision().and_then(NonZeroUsize::new)) {
+            (Some(digits), Some(sig_figs)) => write!(f, "{}", digits.rounded(sig_figs))?,
+            _ => write!(f, "{value}")?,
+        }
+
+        let symbol = self.unit.symbol();
+        if symbol.is_empty() {
+            Ok(())
+        } else {
+            write!(f, " {symbol}")
+        }
+    }
+}
+
+impl<U: Unit + Default + Sized> FloatDisplay for Valued<f64, U> {
+    fn symbol() -> Option<String> {
+        Some(U::default().symbol())
+    }
+}
+
+/// Represents a [`Unit`] raised to the power `P`, e.g., `Power<Seconds, 2>` for seconds squared.
+///
+/// Like any other [`Unit`], this can be used to type a measurement with [`Valued`]:
+///
+/// ```rust
+/// # use sciutil::units::{Float, Seconds, Unit, composition::{Power, Valued}};
+/// #
+/// let area = Valued::from_unit(4.0, Power::<_, 2>::new(Seconds));
+/// assert_eq!(area.get(), 4.0);
+/// assert_eq!(area.unit().symbol(), "s^2");
+///
+/// // If the unit has a default value, this is also available through `Float`.
+/// let area = Valued::<f64, Power<Seconds, 2>>::new(4.0);
+/// assert_eq!(area.unit().symbol(), "s^2");
+/// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
 pub struct Power<T: Unit, const P: i32>(T);
 
 impl<T: Unit, const P: i32> Power<T, P> {
+    /// Construct a new instance of [`Self`], raising `unit` to the power `P`.
+    #[must_use]
+    pub const fn new(unit: T) -> Self {
+        Self(unit)
+    }
+
+    /// Returns the [`Unit`] being raised to the power `P`.
+    #[must_use]
+    pub const fn unit(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the power `P`.
     #[must_use]
     pub const fn power() -> i32 {
         P
@@ -194,6 +577,23 @@ impl<T: Unit, const P: i32> Unit for Power<T, P> {
             format!("{}^({P})", self.0.symbol())
         }
     }
+
+    fn base_symbol(&self) -> String {
+        if P.is_positive() {
+            format!("{}^{P}", self.0.base_symbol())
+        } else {
+            format!("{}^({P})", self.0.base_symbol())
+        }
+    }
+
+    fn to_base(&self) -> f64 {
+        self.0.to_base().powi(P)
+    }
+
+    fn symbol_parts(&self) -> (String, i32) {
+        let (symbol, power) = self.0.symbol_parts();
+        (symbol, power * P)
+    }
 }
 
 // Dummy implementation for testing.
@@ -238,9 +638,9 @@ fn func() {
     let list = UnitList::new(Seconds, UnitListNull)
         .prepend(Seconds)
         .prepend(Seconds)
-        .prepend(Power::<_, 2>(Seconds))
+        .prepend(Power::<_, 2>::new(Seconds))
         .prepend(Seconds)
-        .prepend(Power::<_, -2>(Seconds))
+        .prepend(Power::<_, -2>::new(Seconds))
         .prepend(Seconds)
         .prepend(Seconds)
         .prepend(Seconds)
@@ -267,3 +667,274 @@ fn func() {
     assert_ne!(size_of_val(&big), size_of::<f64>());
     assert_eq!(size_of_val(&big), 8 + 256);
 }
+
+#[test]
+fn unit_list_len_and_iter() {
+    let list = UnitList::new(Seconds, UnitListNull)
+        .prepend(Power::<_, 2>::new(Seconds))
+        .prepend(Seconds)
+        .prepend(Power::<_, -2>::new(Seconds));
+
+    assert_eq!(list.len(), list.flatten_units().len());
+    assert_eq!(list.len(), 4);
+
+    let mut flattened = list.flatten_units();
+    flattened.reverse();
+    let iterated = list.iter().collect::<Vec<_>>();
+    assert_eq!(iterated.len(), flattened.len());
+    for (a, b) in iterated.into_iter().zip(flattened) {
+        assert_eq!(a.symbol(), b.symbol());
+    }
+
+    assert_eq!(UnitList::new(Seconds, UnitListNull).len(), 1);
+}
+
+#[test]
+fn sort_valued() {
+    use super::Meters;
+
+    let mut list = [3.0, -1.5, 10.0, 0.0, 2.5].map(Valued::<f64, Meters>::from_unit_default);
+    list.sort_by(|a, b| a.partial_cmp(b).expect("no values are NaN"));
+
+    assert_eq!(
+        list,
+        [-1.5, 0.0, 2.5, 3.0, 10.0].map(Valued::<f64, Meters>::from_unit_default)
+    );
+    assert!(list[0] < list[1]);
+
+    let nan = Valued::<f64, Meters>::from_unit_default(f64::NAN);
+    assert_eq!(nan.partial_cmp(&list[0]), None);
+}
+
+#[test]
+fn min_max_abs() {
+    use super::{Meters, Unit};
+
+    let short = Valued::<f64, Meters>::new(-3.0);
+    let long = Valued::<f64, Meters>::new(2.0);
+
+    let longest = max(short.abs(), long);
+    assert_eq!(longest, Valued::new(3.0));
+    assert_eq!(longest.unit().symbol(), "m");
+
+    assert_eq!(max(short, long), long);
+    assert_eq!(min(short, long), short);
+    assert_eq!(min(short.abs(), long.abs()).unit(), &Meters);
+}
+
+#[test]
+fn base_unit() {
+    use std::any::TypeId;
+
+    use super::{Centimeters, Hours, Meters, Millimeters, Minutes, Seconds};
+
+    fn base_of<U: BaseUnit>() -> TypeId
+    where
+        U::Base: 'static,
+    {
+        TypeId::of::<U::Base>()
+    }
+
+    assert_eq!(base_of::<Millimeters>(), TypeId::of::<Meters>());
+    assert_eq!(base_of::<Centimeters>(), TypeId::of::<Meters>());
+    assert_eq!(base_of::<Hours>(), TypeId::of::<Seconds>());
+    assert_eq!(Millimeters::base_unit(), Meters);
+    assert_eq!(Hours::base_unit(), Seconds);
+
+    let eq = |lhs: f64, rhs: f64| assert!((lhs - rhs).abs() < 10e-9, "{lhs} != {rhs}");
+    let time = Valued::<f64, Hours>::new(1.5);
+    eq(time.convert::<Minutes>().get(), 90.0);
+    eq(
+        time.convert::<Seconds>().get(),
+        Valued::<f64, Seconds>::from(time).get(),
+    );
+    eq(
+        Valued::<f64, Millimeters>::new(12.0)
+            .convert::<Centimeters>()
+            .get(),
+        1.2,
+    );
+}
+
+#[test]
+fn derived_unit() {
+    use super::{Centimeters, Meters, Seconds};
+
+    crate::derived_unit!(MetersPerSecond = "m/s", [Meters, Power<Seconds, -1>]);
+    crate::derived_unit!(CentimetersPerSecond = "cm/s", [Centimeters, Power<Seconds, -1>]);
+
+    assert_eq!(MetersPerSecond.symbol(), "m/s");
+    assert_eq!(MetersPerSecond.base_symbol(), "m s^(-1)");
+    assert_eq!(
+        Valued::<f64, MetersPerSecond>::symbol().as_deref(),
+        Some("m/s")
+    );
+
+    // Compatible with the product of its units, and with other units derived from them.
+    let product = UnitList::new(Meters, UnitListNull).prepend(Power::<_, -1>::new(Seconds));
+    assert!(MetersPerSecond.is_compatible(&product));
+    assert!(MetersPerSecond.is_compatible(&CentimetersPerSecond));
+    assert!(!MetersPerSecond.is_compatible(&Meters));
+}
+
+#[test]
+fn midpoint() {
+    use super::Meters;
+
+    let start = Valued::<f64, Meters>::new(-1.5);
+    let end = Valued::<f64, Meters>::new(4.5);
+    assert_eq!(start.midpoint(end), Valued::<f64, Meters>::new(1.5));
+    assert_eq!(end.midpoint(start), Valued::<f64, Meters>::new(1.5));
+
+    // Doesn't overflow.
+    let max = Valued::<f64, Meters>::new(f64::MAX);
+    assert_eq!(max.midpoint(max), max);
+}
+
+#[test]
+fn symbol_styled() {
+    use super::{Degrees, Meters, Seconds};
+
+    let velocity = UnitList::new(Meters, UnitListNull).prepend(Power::<_, -1>::new(Seconds));
+    assert_eq!(
+        velocity.symbol_styled(SymbolStyle::Caret),
+        velocity.symbol()
+    );
+    assert_eq!(velocity.symbol_styled(SymbolStyle::Caret), "m s^(-1)");
+    assert_eq!(velocity.symbol_styled(SymbolStyle::Superscript), "m s⁻¹");
+    assert_eq!(velocity.symbol_styled(SymbolStyle::Fraction), "m/s");
+
+    let per_degree = UnitList::new(Meters, UnitListNull)
+        .prepend(Power::<_, -1>::new(Degrees))
+        .prepend(Power::<_, -2>::new(Seconds));
+    assert_eq!(
+        per_degree.symbol_styled(SymbolStyle::Caret),
+        "m °^(-1) s^(-2)"
+    );
+    assert_eq!(
+        per_degree.symbol_styled(SymbolStyle::Superscript),
+        "m °⁻¹ s⁻²"
+    );
+    assert_eq!(per_degree.symbol_styled(SymbolStyle::Fraction), "m/(° s^2)");
+
+    // Nested powers multiply.
+    let frequency_squared = UnitList::new(
+        Power::<_, 2>::new(Power::<_, -1>::new(Seconds)),
+        UnitListNull,
+    );
+    assert_eq!(
+        frequency_squared.symbol_styled(SymbolStyle::Superscript),
+        "s⁻²"
+    );
+    assert_eq!(
+        frequency_squared.symbol_styled(SymbolStyle::Fraction),
+        "1/s^2"
+    );
+}
+
+#[test]
+fn display_rounded() {
+    use super::{Meters, Seconds};
+
+    let length = Valued::<f64, Meters>::new(3.16159);
+    assert_eq!(format!("{length:.2}"), "3.2 m");
+    assert_eq!(format!("{length:.4}"), "3.162 m");
+    assert_eq!(format!("{length}"), "3.16159 m");
+
+    // Ties round to even.
+    assert_eq!(format!("{:.2}", Valued::<f64, Meters>::new(2.25)), "2.2 m");
+    assert_eq!(format!("{:.2}", Valued::<f64, Meters>::new(2.35)), "2.4 m");
+
+    // Composite units and non-finite values.
+    let velocity = Valued::from_unit(
+        12.345,
+        UnitList::new(Meters, UnitListNull).prepend(Power::<_, -1>::new(Seconds)),
+    );
+    assert_eq!(format!("{velocity:.3}"), "12.3 m s^(-1)");
+    assert_eq!(
+        format!("{:.3}", Valued::<f64, Meters>::new(f64::NAN)),
+        "NaN m"
+    );
+}
+
+#[test]
+fn unit_compatibility() {
+    use super::{Meters, Seconds};
+
+    assert!(Meters.is_compatible(&Meters));
+    assert!(!Meters.is_compatible(&Seconds));
+    assert!(!Seconds.is_compatible(&Meters));
+
+    let velocity = UnitList::new(Meters, UnitListNull).prepend(Power::<_, -1>::new(Seconds));
+    assert!(velocity.is_compatible(&velocity));
+    assert!(!velocity.is_compatible(&Meters));
+}
+
+#[test]
+fn hertz() {
+    use super::{Hertz, Seconds};
+
+    let per_second = Power::<_, -1>::new(Seconds);
+    assert!(Hertz.is_compatible(&per_second));
+    assert!(per_second.is_compatible(&Hertz));
+    assert!(!Hertz.is_compatible(&Seconds));
+
+    let rate = Valued::from_unit(50.0, per_second);
+    assert_eq!(rate.unit().symbol(), "s^(-1)");
+    assert_eq!(rate.try_convert(&Hertz), Ok(50.0));
+
+    let frequency = Valued::<f64, Hertz>::from(rate);
+    assert_eq!(frequency, Valued::new(50.0));
+    assert_eq!(
+        <Valued<f64, Hertz> as FloatDisplay>::symbol().as_deref(),
+        Some("Hz")
+    );
+    assert_eq!(Valued::<f64, Power<Seconds, -1>>::from(frequency), rate);
+}
+
+#[test]
+fn try_convert() {
+    use super::{Centimeters, Hours, Meters, Micrometers, Minutes, Seconds};
+
+    let eq = |lhs: f64, rhs: f64| assert!((lhs - rhs).abs() < 10e-9, "{lhs} != {rhs}");
+
+    let length = Valued::<f64, Centimeters>::new(250.0);
+    eq(length.try_convert(&Meters).unwrap(), 2.5);
+    eq(length.try_convert(&Micrometers).unwrap(), 2_500_000.0);
+    eq(length.try_convert(&Centimeters).unwrap(), 250.0);
+    assert_eq!(length.try_convert(&Seconds), Err(IncompatibleUnitsError));
+
+    // Agrees with the typed conversions.
+    eq(Valued::<f64, Meters>::from(length).get(), 2.5);
+
+    let time = Valued::<f64, Hours>::new(2.0);
+    eq(time.try_convert(&Seconds).unwrap(), 7_200.0);
+    eq(Valued::<f64, Seconds>::from(time).get(), 7_200.0);
+    eq(Valued::<f64, Minutes>::from(time).get(), 120.0);
+
+    let velocity = Valued::from_unit(
+        2.5,
+        UnitList::new(Meters, UnitListNull).prepend(Power::<_, -1>::new(Seconds)),
+    );
+    let target = UnitList::new(Centimeters, UnitListNull).prepend(Power::<_, -1>::new(Seconds));
+    eq(velocity.try_convert(&target).unwrap(), 250.0);
+    assert_eq!(velocity.try_convert(&Meters), Err(IncompatibleUnitsError));
+
+    let velocity = Valued::from_unit(
+        90.0,
+        UnitList::new(Meters, UnitListNull).prepend(Power::<_, -1>::new(Minutes)),
+    );
+    let target = UnitList::new(Meters, UnitListNull).prepend(Power::<_, -1>::new(Hours));
+    eq(velocity.try_convert(&target).unwrap(), 5_400.0);
+}
+
+#[test]
+fn clamp_nan_to() {
+    use super::Meters;
+
+    let nan = Valued::<f64, Meters>::new(f64::NAN);
+    assert_eq!(nan.clamp_nan_to(0.0), Valued::<f64, Meters>::new(0.0));
+
+    let finite = Valued::<f64, Meters>::new(-4.0);
+    assert_eq!(finite.clamp_nan_to(0.0), finite);
+}
@@ -96,6 +96,15 @@ macro_rules! float_types {
             fn symbol(&self) -> String {
                 $symbol.to_string()
             }
+
+            // Defined by `base_units!`.
+            fn base_symbol(&self) -> String {
+                Self::base_unit().symbol()
+            }
+
+            fn to_base(&self) -> f64 {
+                Self::TO_BASE
+            }
         }
     };
 
@@ -127,7 +136,10 @@ macro_rules! float_types {
 /// 1. `impl From<$FromType> for $ToType`
 /// 2. `impl From<$ToType> for $FromType`
 /// 3. `impl $FromType { const TO_<$TO_TYPE>: f64 = $factor; }`
-/// 3. `impl $ToType { const TO_<$FROM_TYPE>: f64 = 1.0 / $factor; }`
+/// 4. `impl $ToType { const TO_<$FROM_TYPE>: f64 = 1.0 / $factor; }`
+/// 5. A test for each direction that checks that converting there and back again is a no-op, and
+///    that the factor agrees with the factors to the base unit from `base_units!`. This catches
+///    mistakes in the chained conversions that have to be written out manually.
 macro_rules! conversions {
     // A series of conversions.
     [$(
@@ -147,7 +159,7 @@ macro_rules! conversions {
     // `Meters * (1.0 / 100.0) = Centimeters`.
     (@ $(#[$attribute:meta])* $from:ident * ($( $factor:tt )+) = $to:ident) => {
         conversions!(@@ $(#[$attribute])* $from * ($($factor)+) = $to);
-        conversions!(@@ $(#[$attribute])* $to * (1.0 / $($factor)+) = $from);
+        conversions!(@@ $(#[$attribute])* $to * (1.0 / ($($factor)+)) = $from);
     };
 
     // Complex combined declaration, recursively defining conversion factors.
@@ -179,10 +191,57 @@ macro_rules! conversions {
                     Self::new(value.get() * $from::[<TO_ $to:snake:upper>])
                 }
             }
+
+            #[cfg(test)]
+            #[test]
+            fn [<_conversion_ $from:snake _to_ $to:snake>]() {
+                let eq = |lhs: f64, rhs: f64| {
+                    assert!((lhs / rhs - 1.0).abs() < 10e-12, "{lhs} != {rhs}");
+                };
+
+                let factor = $from::[<TO_ $to:snake:upper>];
+                eq(factor * $to::[<TO_ $from:snake:upper>], 1.0);
+                eq(factor, $from::TO_BASE / $to::TO_BASE);
+            }
         }
     };
 }
 
+/// Defines the base unit of each unit type declared by [`float_types!`], from a list of families
+/// of units.
+///
+/// For each family, it expects `BaseType: UnitType * (factor), ...`, where `factor` converts a
+/// `UnitType` to a `BaseType` (so the `BaseType` itself should have a factor of `1.0`). Every
+/// type declared by [`float_types!`] must appear in exactly one family.
+///
+/// For each `UnitType`, this creates:
+///
+/// 1. `impl $UnitType { const TO_BASE: f64 = $factor; }`
+/// 2. `impl BaseUnit for $UnitType { type Base = $BaseType; }`, used by the [`super::Unit`]
+///    implementation generated by [`float_types!`].
+macro_rules! base_units {
+    [$(
+        $base:ident: $( $unit:ident * ($( $factor:tt )+) ),+ ;
+    )+] => {
+        $($(
+            paste! {
+                impl $unit {
+                    #[doc = "Multiply a [`Self`] by this value to produce a [`" $base "`], the base unit of [`Self`]."]
+                    pub const TO_BASE: f64 = $($factor)+;
+                }
+
+                impl BaseUnit for $unit {
+                    type Base = $base;
+
+                    fn base_unit() -> Self::Base {
+                        $base
+                    }
+                }
+            }
+        )+)+
+    };
+}
+
 /// Generate an implementation of [`super::Float`] for a type, including an implementation of
 /// [`From<f64>`] for the type and vice versa.
 ///
@@ -229,3 +288,80 @@ macro_rules! FloatImpl {
         }
     };
 }
+
+/// Defines a zero-sized [`Unit`][`crate::units::Unit`] type for a unit derived from other units,
+/// e.g., newtons from kilograms, meters, and seconds.
+///
+/// Expects `Name = "symbol", [FirstUnit, SecondUnit, ...]`, optionally preceded by attributes
+/// (like documentation comments) and a visibility. The list holds the units being multiplied
+/// together, which are types, so they may be [`Power`][`crate::units::composition::Power`]s (for
+/// division) or other derived units.
+///
+/// The generated type:
+///
+/// 1. Uses `symbol` for [`Unit::symbol`][`crate::units::Unit::symbol`], but otherwise behaves like
+///    the product of its units (a [`UnitList`][`crate::units::composition::UnitList`] of them, in
+///    the same order), which is its [`BaseUnit`][`crate::units::BaseUnit`]. In particular, it is
+///    compatible with that product and any other unit derived from the same product.
+/// 2. Implements [`FloatDisplay`][`crate::units::FloatDisplay`] through
+///    [`Valued`][`crate::units::composition::Valued`], like any other [`Default`] unit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::{
+/// #     derived_unit,
+/// #     units::{FloatDisplay, Meters, Seconds, Unit, composition::{Power, Valued}},
+/// # };
+/// #
+/// derived_unit!(
+///     /// Represents the unit of meters per second.
+///     pub MetersPerSecond = "m/s", [Meters, Power<Seconds, -1>]
+/// );
+///
+/// assert_eq!(MetersPerSecond.symbol(), "m/s");
+/// assert_eq!(MetersPerSecond.base_symbol(), "m s^(-1)");
+/// assert_eq!(Valued::<f64, MetersPerSecond>::symbol().as_deref(), Some("m/s"));
+/// ```
+#[macro_export]
+macro_rules! derived_unit {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis $unit:ident = $symbol:literal, [$first:ty $(, $rest:ty)* $(,)?]
+    ) => {
+        $(#[$attribute])*
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+        $visibility struct $unit;
+
+        impl $crate::units::Unit for $unit {
+            fn symbol(&self) -> String {
+                $symbol.to_string()
+            }
+
+            fn base_symbol(&self) -> String {
+                <Self as $crate::units::BaseUnit>::base_unit().base_symbol()
+            }
+
+            fn to_base(&self) -> f64 {
+                <Self as $crate::units::BaseUnit>::base_unit().to_base()
+            }
+        }
+
+        impl $crate::units::BaseUnit for $unit {
+            type Base = $crate::derived_unit!(@list $crate::units::composition::UnitListNull; $first $(, $rest)*);
+
+            fn base_unit() -> Self::Base {
+                Default::default()
+            }
+        }
+    };
+
+    // Builds a `UnitList` type out of a list of units, prepending each unit onto the list so far
+    // so that the units appear in the same order as they were written.
+    (@list $list:ty; $next:ty $(, $rest:ty)*) => {
+        $crate::derived_unit!(@list $crate::units::composition::UnitList<$next, $list>; $($rest),*)
+    };
+    (@list $list:ty;) => {
+        $list
+    };
+}
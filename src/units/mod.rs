@@ -9,14 +9,23 @@
 //! `units`: Traits and wrapper structs to mark arbitrary floating-point values as physical
 //! measurements.
 //!
-//! In particular, see [`Float`], [`UncertainFloat`], and [`Per`].
+//! In particular, see [`Float`], [`FloatDisplay`], [`UncertainFloat`], and [`Per`].
 
 #[macro_use]
 mod macros;
 
 pub mod composition;
+#[cfg(test)]
+mod test;
 
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    num::{NonZeroU32, NonZeroUsize},
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::rounding::digits::DigitsValue;
 
 use paste::paste;
 #[cfg(any(feature = "serde", test))]
@@ -33,6 +42,167 @@ pub trait Unit {
     /// implementations from other crates may follow different recommendations.
     #[must_use]
     fn symbol(&self) -> String;
+
+    /// The symbol of the coherent unit that [`Self`] is a multiple of, e.g., `"m"` for
+    /// centimeters.
+    ///
+    /// By default, [`Self`] is its own base unit.
+    #[must_use]
+    fn base_symbol(&self) -> String {
+        self.symbol()
+    }
+
+    /// Multiply a value in [`Self`] by this factor to convert it to the unit of
+    /// [`Self::base_symbol`].
+    ///
+    /// By default, [`Self`] is its own base unit, so this is `1.0`.
+    #[must_use]
+    fn to_base(&self) -> f64 {
+        1.0
+    }
+
+    /// Whether a quantity in this unit can be combined with (e.g., added to) a quantity in `other`.
+    ///
+    /// By default, this compares the units' [base symbols][`Self::base_symbol`], so units that
+    /// are multiples of each other (like meters and centimeters) are compatible, as are
+    /// [`composition::UnitList`]s of the same units in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{Centimeters, Meters, Seconds, Unit};
+    /// #
+    /// assert!(Meters.is_compatible(&Meters));
+    /// assert!(Meters.is_compatible(&Centimeters));
+    /// assert!(!Meters.is_compatible(&Seconds));
+    /// ```
+    #[must_use]
+    fn is_compatible(&self, other: &dyn Unit) -> bool {
+        self.base_symbol() == other.base_symbol()
+    }
+
+    /// Splits the symbol of [`Self`] into the symbol of the unit being raised to a power and the
+    /// power it is raised to, e.g., `("s", -2)` for [`composition::Power<Seconds, -2>`].
+    ///
+    /// By default, [`Self`] is not raised to any power, so this is [`Self::symbol`] and `1`.
+    #[must_use]
+    fn symbol_parts(&self) -> (String, i32) {
+        (self.symbol(), 1)
+    }
+
+    /// The symbol of [`Self`], with any powers rendered in the given [`SymbolStyle`].
+    ///
+    /// By default, this renders [`Self::symbol_parts`] with [`SymbolStyle::apply`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{Meters, Seconds, SymbolStyle, Unit, composition::Power};
+    /// #
+    /// let per_second = Power::<_, -1>::new(Seconds);
+    ///
+    /// assert_eq!(per_second.symbol_styled(SymbolStyle::Caret), "s^(-1)");
+    /// assert_eq!(per_second.symbol_styled(SymbolStyle::Superscript), "s⁻¹");
+    /// assert_eq!(per_second.symbol_styled(SymbolStyle::Fraction), "1/s");
+    ///
+    /// // Units that aren't raised to a power are the same in every style.
+    /// assert_eq!(Meters.symbol_styled(SymbolStyle::Superscript), "m");
+    /// ```
+    #[must_use]
+    fn symbol_styled(&self, style: SymbolStyle) -> String {
+        let (symbol, power) = self.symbol_parts();
+        style.apply(&symbol, power)
+    }
+}
+
+/// How to render units raised to a power in [`Unit::symbol_styled`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum SymbolStyle {
+    /// Plain ASCII with a caret, wrapping negative powers in parentheses: `m s^(-2)`. This matches
+    /// [`Unit::symbol`].
+    #[default]
+    Caret,
+
+    /// Unicode superscripts: `m s⁻²`.
+    Superscript,
+
+    /// Negative powers become a denominator, which is wrapped in parentheses if it contains more
+    /// than one unit: `m/s^2` or `kg/(m s^2)`.
+    Fraction,
+}
+
+impl SymbolStyle {
+    /// Renders `symbol` raised to `power` in [`Self`]. A power of `1` is never rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::SymbolStyle;
+    /// #
+    /// assert_eq!(SymbolStyle::Caret.apply("m", 2), "m^2");
+    /// assert_eq!(SymbolStyle::Caret.apply("m", -2), "m^(-2)");
+    /// assert_eq!(SymbolStyle::Superscript.apply("m", -12), "m⁻¹²");
+    /// assert_eq!(SymbolStyle::Fraction.apply("m", -2), "1/m^2");
+    /// assert_eq!(SymbolStyle::Fraction.apply("m", 1), "m");
+    /// ```
+    #[must_use]
+    pub fn apply(self, symbol: &str, power: i32) -> String {
+        match self {
+            _ if power == 1 => symbol.to_string(),
+            Self::Caret if power.is_positive() => format!("{symbol}^{power}"),
+            Self::Caret => format!("{symbol}^({power})"),
+            Self::Superscript => {
+                let superscript: String = power
+                    .to_string()
+                    .chars()
+                    .map(|char| match char {
+                        '-' => '⁻',
+                        '0' => '⁰',
+                        '1' => '¹',
+                        '2' => '²',
+                        '3' => '³',
+                        '4' => '⁴',
+                        '5' => '⁵',
+                        '6' => '⁶',
+                        '7' => '⁷',
+                        '8' => '⁸',
+                        _ => '⁹',
+                    })
+                    .collect();
+
+                format!("{symbol}{superscript}")
+            }
+            Self::Fraction if power.is_negative() => {
+                format!("1/{}", Self::Caret.apply(symbol, -power))
+            }
+            Self::Fraction => Self::Caret.apply(symbol, power),
+        }
+    }
+}
+
+/// A [`Unit`] that is a multiple of a known, statically typed base unit, e.g., [`Centimeters`] and
+/// [`Millimeters`] are both multiples of [`Meters`].
+///
+/// This allows generic code to route conversions through the base unit, as in
+/// [`composition::Valued::convert`]. [`Unit::base_symbol`] and [`Unit::to_base`] provide the same
+/// information for [`Unit`] trait objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::units::{BaseUnit, Hours, Meters, Millimeters, Seconds, Unit};
+/// #
+/// assert_eq!(Millimeters::base_unit(), Meters);
+/// assert_eq!(Hours::base_unit(), Seconds);
+/// assert_eq!(Millimeters.base_symbol(), Meters.symbol());
+/// ```
+pub trait BaseUnit: Unit {
+    /// The base unit that [`Self`] is a multiple of.
+    type Base: Unit;
+
+    /// Returns the base unit of [`Self`].
+    #[must_use]
+    fn base_unit() -> Self::Base;
 }
 
 /// Represents a numeric value with an associated [`Unit`].
@@ -60,6 +230,53 @@ pub trait Float: From<f64> + Into<f64> {
     /// Returns the internal [`f64`] representation of [`Self`].
     #[must_use]
     fn get(&self) -> f64;
+
+    /// Replaces a NaN or infinite value with `fallback`, keeping the type (and so the unit) of
+    /// [`Self`].
+    ///
+    /// This is useful for sanitizing data before passing it to code that panics on non-finite
+    /// values, such as [`crate::rounding::digits::Digits::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{Float, Meters, composition::Valued};
+    /// #
+    /// assert_eq!(f64::NAN.clamp_nan_to(0.0), 0.0);
+    /// assert_eq!(f64::NEG_INFINITY.clamp_nan_to(-1.0), -1.0);
+    /// assert_eq!(2.5.clamp_nan_to(0.0), 2.5);
+    ///
+    /// let length = Valued::<f64, Meters>::new(f64::INFINITY).clamp_nan_to(0.0);
+    /// assert_eq!(length, Valued::<f64, Meters>::new(0.0));
+    /// ```
+    #[must_use]
+    fn clamp_nan_to(self, fallback: f64) -> Self {
+        if self.get().is_finite() {
+            self
+        } else {
+            Self::new(fallback)
+        }
+    }
+
+    /// Calculates the value halfway between [`Self`] and `other`, keeping the type (and so the
+    /// unit) of [`Self`].
+    ///
+    /// This delegates to [`f64::midpoint`], which avoids overflowing for very large values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{Float, Meters, composition::Valued};
+    /// #
+    /// let start = Valued::<f64, Meters>::new(2.0);
+    /// let end = Valued::<f64, Meters>::new(5.0);
+    ///
+    /// assert_eq!(start.midpoint(end), Valued::<f64, Meters>::new(3.5));
+    /// ```
+    #[must_use]
+    fn midpoint(self, other: Self) -> Self {
+        Self::new(self.get().midpoint(other.get()))
+    }
 }
 
 impl Float for f64 {
@@ -72,6 +289,22 @@ impl Float for f64 {
     }
 }
 
+/// A [`Float`] that knows the symbol of its physical unit, for the sake of display.
+///
+/// Unit-less types (like [`f64`]) implement this by returning [`None`], so that generic code can
+/// display any [`FloatDisplay`] without special-casing the unit-less case.
+pub trait FloatDisplay: Float {
+    /// The symbol of the physical unit of [`Self`], or [`None`] if [`Self`] is unit-less.
+    #[must_use]
+    fn symbol() -> Option<String>;
+}
+
+impl FloatDisplay for f64 {
+    fn symbol() -> Option<String> {
+        None
+    }
+}
+
 /// Represents a value with an associated absolute uncertainty.
 ///
 /// # Examples
@@ -123,6 +356,354 @@ impl<F: Float> UncertainFloat<F> {
     pub fn max(&self) -> F {
         F::new(self.value.get() + self.uncertainty.get().abs())
     }
+
+    /// Multiplies [`Self`] by an exact `factor`, such as a unit conversion factor.
+    ///
+    /// The value is multiplied by `factor` and the uncertainty by `|factor|`, so the uncertainty
+    /// stays positive even for a negative `factor`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// let measurement = UncertainFloat::new(10.0, 1.0);
+    /// assert_eq!(measurement.scale(2.0), UncertainFloat::new(20.0, 2.0));
+    /// assert_eq!(measurement.scale(-2.0), UncertainFloat::new(-20.0, 2.0));
+    /// ```
+    #[must_use]
+    pub fn scale(&self, factor: f64) -> Self {
+        Self::new(
+            F::new(self.value.get() * factor),
+            F::new(self.uncertainty.get() * factor.abs()),
+        )
+    }
+
+    /// Adds an exact `offset` to [`Self`], such as a calibration offset.
+    ///
+    /// Only the value changes, because adding an exact number does not add any uncertainty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// let measurement = UncertainFloat::new(10.0, 1.0);
+    /// assert_eq!(measurement.shift(5.0), UncertainFloat::new(15.0, 1.0));
+    /// assert_eq!(measurement.shift(-5.0).scale(2.0), UncertainFloat::new(10.0, 2.0));
+    /// ```
+    #[must_use]
+    pub fn shift(&self, offset: f64) -> Self {
+        Self::new(
+            F::new(self.value.get() + offset),
+            F::new(self.uncertainty.get()),
+        )
+    }
+
+    /// Adds an exact `delta` to [`Self`], leaving the uncertainty unchanged.
+    ///
+    /// This is the same as [`Self::shift`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// let measurement = UncertainFloat::new(10.0, 1.0);
+    /// assert_eq!(measurement.offset(-2.5), UncertainFloat::new(7.5, 1.0));
+    /// assert_eq!(measurement.offset(-2.5), measurement.shift(-2.5));
+    /// ```
+    #[must_use]
+    pub fn offset(&self, delta: f64) -> Self {
+        self.shift(delta)
+    }
+
+    /// Raises [`Self`] to the power of an exact `exponent`, propagating the uncertainty through
+    /// the power rule.
+    ///
+    /// The relative uncertainty is scaled by the magnitude of the exponent: `δ(x^n)/|x^n| =
+    /// |n|·δx/|x|`. The result is unit-less, because `F` is not necessarily compatible with its
+    /// own powers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// // `2 * 3 * 0.5 = 3`
+    /// let side = UncertainFloat::new(3.0, 0.5);
+    /// assert_eq!(side.powf(2.0), UncertainFloat::new(9.0, 3.0));
+    ///
+    /// // The sign of the exponent doesn't matter to the uncertainty: `|-1| * 0.5 / 2^2 = 0.125`.
+    /// assert_eq!(UncertainFloat::new(2.0, 0.5).powf(-1.0), UncertainFloat::new(0.5, 0.125));
+    /// ```
+    #[must_use]
+    pub fn powf(&self, exponent: f64) -> UncertainFloat<f64> {
+        let value = self.value.get();
+
+        UncertainFloat::new(
+            value.powf(exponent),
+            (exponent * value.powf(exponent - 1.0)).abs() * self.uncertainty.get(),
+        )
+    }
+
+    /// Raises [`Self`] to an integer power, propagating the uncertainty through the power rule.
+    ///
+    /// Like [`Self::powf`], the relative uncertainty is scaled by the magnitude of the exponent:
+    /// `δ(x^n)/|x^n| = |n|·δx/|x|`, and the result is unit-less. Integer powers are computed with
+    /// [`f64::powi`], which is faster and often more exact than [`f64::powf`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// // `3 * 2^2 * 0.1 = 1.2`
+    /// let side = UncertainFloat::new(2.0, 0.1);
+    /// assert_eq!(side.powi(3), UncertainFloat::new(8.0, 1.2000000000000002));
+    /// assert_eq!(side.powi(3), side.powf(3.0));
+    ///
+    /// assert_eq!(side.powi(0), UncertainFloat::new(1.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn powi(&self, exponent: i32) -> UncertainFloat<f64> {
+        let value = self.value.get();
+
+        UncertainFloat::new(
+            value.powi(exponent),
+            (f64::from(exponent) * value.powi(exponent.saturating_sub(1))).abs()
+                * self.uncertainty.get(),
+        )
+    }
+
+    /// Takes the square root of [`Self`], propagating the uncertainty through the power rule.
+    ///
+    /// Equivalent to [`Self::powf`] with an exponent of `0.5`, so the relative uncertainty is
+    /// halved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// // `0.5 * 2 / sqrt(16) = 0.25`
+    /// let area = UncertainFloat::new(16.0, 2.0);
+    /// assert_eq!(area.sqrt(), UncertainFloat::new(4.0, 0.25));
+    /// ```
+    #[must_use]
+    pub fn sqrt(&self) -> UncertainFloat<f64> {
+        let root = self.value.get().sqrt();
+
+        UncertainFloat::new(root, self.uncertainty.get() / (2.0 * root))
+    }
+
+    /// Rounds the uncertainty of [`Self`] to `sig_figs` significant figures and the value to the
+    /// same place, keeping the result numeric for further computation.
+    ///
+    /// This is the numeric counterpart to [`crate::rounding::round_with_uncertainty`], except that
+    /// the number of significant figures is chosen by the caller. If either the value or the
+    /// uncertainty are not finite, [`Self`] is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::num::NonZeroU32;
+    /// #
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// let measurement = UncertainFloat::new(1_024.051_123, 0.015_555_312);
+    ///
+    /// let two = NonZeroU32::new(2).unwrap();
+    /// assert_eq!(measurement.rounded(two), UncertainFloat::new(1_024.051, 0.016));
+    ///
+    /// let one = NonZeroU32::new(1).unwrap();
+    /// assert_eq!(measurement.rounded(one), UncertainFloat::new(1_024.05, 0.02));
+    /// ```
+    #[must_use]
+    pub fn rounded(&self, sig_figs: NonZeroU32) -> Self {
+        let (DigitsValue::Finite(value), DigitsValue::Finite(uncertainty)) = (
+            DigitsValue::new(&self.value),
+            DigitsValue::new(&self.uncertainty),
+        ) else {
+            return Self::new(F::new(self.value.get()), F::new(self.uncertainty.get()));
+        };
+        let sig_figs = NonZeroUsize::try_from(sig_figs).unwrap_or(NonZeroUsize::MAX);
+
+        // Rounding up may add a digit (e.g., `0.0996` to `0.10`), so find the place again.
+        let uncertainty =
+            uncertainty.round_to_place(uncertainty.significant_figure_place(sig_figs));
+        let place = uncertainty.significant_figure_place(sig_figs);
+
+        Self::new(
+            F::new(value.round_to_place(place).to_f64()),
+            F::new(uncertainty.round_to_place(place).to_f64()),
+        )
+    }
+
+    /// Checks whether `reference` lies within the uncertainty band of [`Self`], that is, between
+    /// [`Self::min`] and [`Self::max`] inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// let gravity = UncertainFloat::new(9.7, 0.2);
+    /// assert!(gravity.agrees_with(9.81));
+    /// assert!(!gravity.agrees_with(10.5));
+    /// ```
+    #[must_use]
+    pub fn agrees_with(&self, reference: f64) -> bool {
+        self.min().get() <= reference && reference <= self.max().get()
+    }
+
+    /// Returns how many standard uncertainties `reference` is away from the value of [`Self`].
+    ///
+    /// A result of at most `1` means that `reference` is within the uncertainty band (see
+    /// [`Self::agrees_with`]). If the uncertainty is zero, the result is infinite for any other
+    /// `reference`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::UncertainFloat;
+    /// #
+    /// let gravity = UncertainFloat::new(9.7, 0.2);
+    /// assert!((gravity.sigmas_from(9.81) - 0.55).abs() < 1e-10);
+    /// assert!((gravity.sigmas_from(10.5) - 4.0).abs() < 1e-10);
+    /// ```
+    #[must_use]
+    pub fn sigmas_from(&self, reference: f64) -> f64 {
+        (reference - self.value.get()).abs() / self.uncertainty.get().abs()
+    }
+
+    /// Formats [`Self`] like its [`Display`] implementation, but with the symbol of the physical
+    /// unit of `F` appended, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sciutil::units::{Float, Meters, UncertainFloat, composition::Valued};
+    /// #
+    /// let length = UncertainFloat::new(
+    ///     Valued::<f64, Meters>::new(5.0),
+    ///     Valued::<f64, Meters>::new(1.0),
+    /// );
+    /// assert_eq!(length.to_string_with_units(), "5 ± 1 m");
+    ///
+    /// // Unit-less values are displayed as normal.
+    /// assert_eq!(UncertainFloat::new(5.0, 1.0).to_string_with_units(), "5 ± 1");
+    /// ```
+    #[must_use]
+    pub fn to_string_with_units(&self) -> String
+    where
+        F: FloatDisplay,
+    {
+        F::symbol().map_or_else(|| self.to_string(), |symbol| format!("{self} {symbol}"))
+    }
+}
+
+/// Adds the values, adding their absolute uncertainties in quadrature: `sqrt(δa^2 + δb^2)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::units::UncertainFloat;
+/// #
+/// let sum = UncertainFloat::new(5.0, 0.1) + UncertainFloat::new(3.0, 0.2);
+/// assert_eq!(*sum.value(), 8.0);
+/// assert!((sum.uncertainty() - 0.2236).abs() < 1e-4);
+/// ```
+impl<F: Float> Add for UncertainFloat<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            F::new(self.value.get() + rhs.value.get()),
+            F::new(self.uncertainty.get().hypot(rhs.uncertainty.get())),
+        )
+    }
+}
+
+/// Subtracts the values, adding their absolute uncertainties in quadrature: `sqrt(δa^2 + δb^2)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::units::UncertainFloat;
+/// #
+/// let difference = UncertainFloat::new(5.0, 0.3) - UncertainFloat::new(3.0, 0.4);
+/// assert_eq!(difference, UncertainFloat::new(2.0, 0.5));
+/// ```
+impl<F: Float> Sub for UncertainFloat<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            F::new(self.value.get() - rhs.value.get()),
+            F::new(self.uncertainty.get().hypot(rhs.uncertainty.get())),
+        )
+    }
+}
+
+/// Multiplies the values, adding their relative uncertainties in quadrature:
+/// `δ(ab)/|ab| = sqrt((δa/a)^2 + (δb/b)^2)`.
+///
+/// This is calculated as the equivalent `sqrt((b δa)^2 + (a δb)^2)`, so that a zero value doesn't
+/// produce a NaN uncertainty. Like [`UncertainFloat::powf`], the result is unit-less, because `F`
+/// is not necessarily compatible with its own products.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::units::UncertainFloat;
+/// #
+/// // 3% and 4% relative uncertainty make 5%.
+/// let product = UncertainFloat::new(10.0, 0.3) * UncertainFloat::new(2.0, 0.08);
+/// assert_eq!(*product.value(), 20.0);
+/// assert!((product.uncertainty() - 1.0).abs() < 1e-10);
+/// ```
+impl<F: Float> Mul for UncertainFloat<F> {
+    type Output = UncertainFloat<f64>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.value.get(), rhs.value.get());
+
+        UncertainFloat::new(
+            a * b,
+            (b * self.uncertainty.get()).hypot(a * rhs.uncertainty.get()),
+        )
+    }
+}
+
+/// Divides the values, adding their relative uncertainties in quadrature:
+/// `δ(a/b)/|a/b| = sqrt((δa/a)^2 + (δb/b)^2)`.
+///
+/// This is calculated as the equivalent `sqrt((δa/b)^2 + (a δb/b^2)^2)`, so that a zero numerator
+/// doesn't produce a NaN uncertainty. Like [`UncertainFloat::powf`], the result is unit-less,
+/// because `F` is not necessarily compatible with its own quotients.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::units::UncertainFloat;
+/// #
+/// // 3% and 4% relative uncertainty make 5%.
+/// let quotient = UncertainFloat::new(10.0, 0.3) / UncertainFloat::new(2.0, 0.08);
+/// assert_eq!(*quotient.value(), 5.0);
+/// assert!((quotient.uncertainty() - 0.25).abs() < 1e-10);
+/// ```
+impl<F: Float> Div for UncertainFloat<F> {
+    type Output = UncertainFloat<f64>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.value.get(), rhs.value.get());
+
+        UncertainFloat::new(
+            a / b,
+            (self.uncertainty.get() / b).hypot(a * rhs.uncertainty.get() / b.powi(2)),
+        )
+    }
 }
 
 impl<F: Float> Display for UncertainFloat<F> {
@@ -131,6 +712,58 @@ impl<F: Float> Display for UncertainFloat<F> {
     }
 }
 
+/// Represents the `ORDER`th derivative of `Num` with respect to `Den`, e.g., `Per<Meters, Seconds,
+/// 2>` (with each typed by [`composition::Valued`]) for an acceleration in meters per second
+/// squared.
+///
+/// This is the most correct typing for the output of the functions in
+/// [`crate::statistics::derivatives`], see
+/// [`first_order_per`][`crate::statistics::derivatives::first_order_per`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciutil::units::{Float, FloatDisplay, Meters, Per, Seconds, composition::Valued};
+/// #
+/// type Acceleration = Per<Valued<f64, Meters>, Valued<f64, Seconds>, 2>;
+///
+/// let gravity = Acceleration::new(9.81);
+/// assert_eq!(gravity.get(), 9.81);
+/// assert_eq!(Acceleration::symbol().as_deref(), Some("m / s^2"));
+///
+/// // Unit-less values are displayed as one.
+/// assert_eq!(Per::<f64, Valued<f64, Seconds>, 1>::symbol().as_deref(), Some("1 / s"));
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default)]
+pub struct Per<Num: Float, Den: Float, const ORDER: u32>(f64, PhantomData<fn() -> (Num, Den)>);
+
+FloatImpl! {
+    impl<( Num: Float, Den: Float, const ORDER: u32 )> Float for Per<( Num, Den, ORDER )> {
+        fn new(value: f64) -> Self {
+            Self(value, PhantomData)
+        }
+
+        fn get(&self) -> f64 {
+            self.0
+        }
+    }
+}
+
+impl<Num: FloatDisplay, Den: FloatDisplay, const ORDER: u32> FloatDisplay for Per<Num, Den, ORDER> {
+    fn symbol() -> Option<String> {
+        let Some(denominator) = Den::symbol().filter(|_| ORDER != 0) else {
+            return Num::symbol();
+        };
+        let numerator = Num::symbol().unwrap_or_else(|| "1".to_string());
+
+        Some(if ORDER == 1 {
+            format!("{numerator} / {denominator}")
+        } else {
+            format!("{numerator} / {denominator}^{ORDER}")
+        })
+    }
+}
+
 float_types![
     (Day, "d",),
     (Hour, "hr",),
@@ -141,10 +774,17 @@ float_types![
     (Millimeter, "mm",),
     (Micrometer, "μm",),
     (Degree, "°",),
+    (
+        /// Represents the unit of hertz, the SI unit of frequency, equal to one inverse second.
+        Hertz,
+        "Hz",
+        "hertz",
+        "hertz",
+    ),
 ];
 
 conversions![
-    (Seconds * 60.0 = Minutes * 60.0 = Hours * 24.0 = Days),
+    (Days * 24.0 = Hours * 60.0 = Minutes * 60.0 = Seconds),
     (Meters * 100.0 = Centimeters * 10.0 = Millimeters * 1_000.0 = Micrometers),
     // These should really be gotten for free from the above two lines, but doing it manually works
     // for now.
@@ -155,3 +795,48 @@ conversions![
     (Meters * (Meters::TO_MILLIMETERS * Millimeters::TO_MICROMETERS) = Micrometers),
     (Centimeters * (Centimeters::TO_MILLIMETERS * Millimeters::TO_MICROMETERS) = Micrometers),
 ];
+
+base_units![
+    Seconds: Seconds * (1.0),
+        Minutes * (Minutes::TO_SECONDS),
+        Hours * (Hours::TO_SECONDS),
+        Days * (Days::TO_SECONDS);
+    Meters: Meters * (1.0),
+        Centimeters * (Centimeters::TO_METERS),
+        Millimeters * (Millimeters::TO_METERS),
+        Micrometers * (Micrometers::TO_METERS);
+    Degrees: Degrees * (1.0);
+];
+
+// Hertz is a derived unit, so its base unit is a composition that `base_units!` cannot name.
+impl Hertz {
+    /// Multiply a [`Self`] by this value to produce a [`composition::Power<Seconds, -1>`], the base
+    /// unit of [`Self`].
+    pub const TO_BASE: f64 = 1.0;
+}
+
+impl BaseUnit for Hertz {
+    type Base = composition::Power<Seconds, -1>;
+
+    fn base_unit() -> Self::Base {
+        composition::Power::new(Seconds)
+    }
+}
+
+impl From<composition::Valued<f64, composition::Power<Seconds, -1>>>
+    for composition::Valued<f64, Hertz>
+{
+    #[inline]
+    fn from(value: composition::Valued<f64, composition::Power<Seconds, -1>>) -> Self {
+        Self::new(value.get() * Hertz::TO_BASE.recip())
+    }
+}
+
+impl From<composition::Valued<f64, Hertz>>
+    for composition::Valued<f64, composition::Power<Seconds, -1>>
+{
+    #[inline]
+    fn from(value: composition::Valued<f64, Hertz>) -> Self {
+        Self::new(value.get() * Hertz::TO_BASE)
+    }
+}
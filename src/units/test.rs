@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MPL-2.0
+//
+// Copyright © 2025 RemasteredArch
+//
+// This Source Code Form is subject to the terms of the Mozilla Public License, version 2.0. If a
+// copy of the Mozilla Public License was not distributed with this file, You can obtain one at
+// <https://mozilla.org/MPL/2.0/>.
+
+use super::UncertainFloat;
+
+#[test]
+fn uncertain_float_arithmetic() {
+    let a = UncertainFloat::new(0.0, 0.3);
+    let b = UncertainFloat::new(2.0, 0.4);
+
+    // A zero value still has a meaningful uncertainty.
+    let product = a * b;
+    assert_eq!(product.value().to_bits(), 0.0_f64.to_bits());
+    assert!((product.uncertainty() - 0.6).abs() < 1e-12);
+
+    let quotient = a / b;
+    assert_eq!(quotient.value().to_bits(), 0.0_f64.to_bits());
+    assert!((quotient.uncertainty() - 0.15).abs() < 1e-12);
+
+    // Adding and subtracting propagate the same uncertainty.
+    assert_eq!(a + b, UncertainFloat::new(2.0, 0.5));
+    assert_eq!(b - a, UncertainFloat::new(2.0, 0.5));
+
+    // Exact values stay exact.
+    let exact = UncertainFloat::new(4.0, 0.0) / UncertainFloat::new(2.0, 0.0);
+    assert_eq!(exact, UncertainFloat::new(2.0, 0.0));
+}
+
+#[test]
+fn uncertain_float_agreement() {
+    let measurement = UncertainFloat::new(10.0, 0.5);
+
+    // Inside the uncertainty band.
+    assert!(measurement.agrees_with(10.2));
+    assert!(measurement.sigmas_from(10.2) < 1.0);
+
+    // On the boundary, from either side.
+    for reference in [9.5, 10.5] {
+        assert!(measurement.agrees_with(reference));
+        assert_eq!(
+            measurement.sigmas_from(reference).to_bits(),
+            1.0_f64.to_bits()
+        );
+    }
+
+    // Far outside the uncertainty band.
+    assert!(!measurement.agrees_with(13.0));
+    assert!(!measurement.agrees_with(7.0));
+    assert_eq!(measurement.sigmas_from(7.0).to_bits(), 6.0_f64.to_bits());
+
+    // `NaN` agrees with nothing.
+    assert!(!measurement.agrees_with(f64::NAN));
+    assert!(measurement.sigmas_from(f64::NAN).is_nan());
+}